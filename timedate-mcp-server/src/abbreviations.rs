@@ -0,0 +1,53 @@
+//! Embedded table of common timezone abbreviations keyed by UTC offset
+//!
+//! Abbreviations are not unique worldwide (CST is both US Central and China Standard Time), so the
+//! table lists the most widely used meaning for each offset.
+
+/// Common abbreviations in use at each UTC offset, in minutes east of UTC
+const OFFSET_NAMES: &[(i32, &[&str])] = &[
+    (-11 * 60, &["SST"]),
+    (-10 * 60, &["HST"]),
+    (-9 * 60 - 30, &["MART"]),
+    (-9 * 60, &["AKST", "HDT"]),
+    (-8 * 60, &["PST", "AKDT"]),
+    (-7 * 60, &["MST", "PDT"]),
+    (-6 * 60, &["CST", "MDT"]),
+    (-5 * 60, &["EST", "CDT"]),
+    (-4 * 60, &["AST", "EDT"]),
+    (-3 * 60 - 30, &["NST"]),
+    (-3 * 60, &["ADT", "BRT", "ART"]),
+    (-2 * 60 - 30, &["NDT"]),
+    (-60, &["AZOT", "CVT"]),
+    (0, &["GMT", "UTC", "WET"]),
+    (60, &["CET", "BST", "WAT", "WEST"]),
+    (2 * 60, &["EET", "CEST", "CAT", "SAST"]),
+    (3 * 60, &["MSK", "EEST", "EAT", "TRT"]),
+    (3 * 60 + 30, &["IRST"]),
+    (4 * 60, &["GST", "AZT"]),
+    (4 * 60 + 30, &["AFT"]),
+    (5 * 60, &["PKT", "UZT"]),
+    (5 * 60 + 30, &["IST"]),
+    (5 * 60 + 45, &["NPT"]),
+    (6 * 60, &["BST"]),
+    (6 * 60 + 30, &["MMT"]),
+    (7 * 60, &["ICT", "WIB"]),
+    (8 * 60, &["CST", "AWST", "SGT", "HKT"]),
+    (9 * 60, &["JST", "KST"]),
+    (9 * 60 + 30, &["ACST"]),
+    (10 * 60, &["AEST", "ChST"]),
+    (10 * 60 + 30, &["ACDT"]),
+    (11 * 60, &["AEDT"]),
+    (12 * 60, &["NZST", "FJT"]),
+    (13 * 60, &["NZDT"]),
+    (13 * 60 + 45, &["CHADT"]),
+    (14 * 60, &["LINT"]),
+];
+
+/// Common abbreviations for an offset given in minutes east of UTC
+pub(crate) fn names_for_offset(offset_minutes: i32) -> &'static [&'static str] {
+    OFFSET_NAMES
+        .iter()
+        .find(|(minutes, _)| *minutes == offset_minutes)
+        .map(|(_, names)| *names)
+        .unwrap_or(&[])
+}
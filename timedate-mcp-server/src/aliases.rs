@@ -0,0 +1,164 @@
+//! Embedded tables mapping common non-IANA timezone names to IANA zones
+//!
+//! Names in the IANA database always win, so legacy zones such as "EST" or "CET" keep their
+//! database meaning and are not listed here. Windows names follow the CLDR `windowsZones` mapping
+//! for the default territory.
+
+/// Abbreviations and short names, upper case. Entries with several zones are ambiguous
+/// (CST is both US Central and China Standard Time) and resolve to none of them.
+const ABBREVIATIONS: &[(&str, &[&str])] = &[
+    ("ET", &["America/New_York"]),
+    ("EDT", &["America/New_York"]),
+    ("CT", &["America/Chicago"]),
+    (
+        "CST",
+        &["America/Chicago", "Asia/Shanghai", "America/Havana"],
+    ),
+    ("CDT", &["America/Chicago"]),
+    ("MT", &["America/Denver"]),
+    ("MDT", &["America/Denver"]),
+    ("PT", &["America/Los_Angeles"]),
+    ("PST", &["America/Los_Angeles"]),
+    ("PDT", &["America/Los_Angeles"]),
+    ("AKST", &["America/Anchorage"]),
+    ("AKDT", &["America/Anchorage"]),
+    ("AST", &["America/Halifax", "Asia/Riyadh"]),
+    ("ADT", &["America/Halifax"]),
+    ("NST", &["America/St_Johns"]),
+    ("NDT", &["America/St_Johns"]),
+    ("BRT", &["America/Sao_Paulo"]),
+    ("ART", &["America/Argentina/Buenos_Aires"]),
+    ("BST", &["Europe/London", "Asia/Dhaka"]),
+    ("IST", &["Asia/Kolkata", "Europe/Dublin", "Asia/Jerusalem"]),
+    ("WEST", &["Europe/Lisbon"]),
+    ("CEST", &["Europe/Berlin"]),
+    ("EEST", &["Europe/Athens"]),
+    ("MSK", &["Europe/Moscow"]),
+    ("TRT", &["Europe/Istanbul"]),
+    ("WAT", &["Africa/Lagos"]),
+    ("CAT", &["Africa/Maputo"]),
+    ("SAST", &["Africa/Johannesburg"]),
+    ("EAT", &["Africa/Nairobi"]),
+    ("IRST", &["Asia/Tehran"]),
+    ("GST", &["Asia/Dubai", "Atlantic/South_Georgia"]),
+    ("PKT", &["Asia/Karachi"]),
+    ("NPT", &["Asia/Kathmandu"]),
+    ("ICT", &["Asia/Bangkok"]),
+    ("WIB", &["Asia/Jakarta"]),
+    ("SGT", &["Asia/Singapore"]),
+    ("HKT", &["Asia/Hong_Kong"]),
+    ("AWST", &["Australia/Perth"]),
+    ("JST", &["Asia/Tokyo"]),
+    ("KST", &["Asia/Seoul"]),
+    ("ACST", &["Australia/Adelaide"]),
+    ("ACDT", &["Australia/Adelaide"]),
+    ("AEST", &["Australia/Sydney"]),
+    ("AEDT", &["Australia/Sydney"]),
+    ("NZST", &["Pacific/Auckland"]),
+    ("NZDT", &["Pacific/Auckland"]),
+    ("SST", &["Pacific/Pago_Pago", "Asia/Singapore"]),
+];
+
+/// Windows timezone ids and the IANA zone CLDR maps them to
+const WINDOWS_ZONES: &[(&str, &str)] = &[
+    ("Dateline Standard Time", "Etc/GMT+12"),
+    ("UTC-11", "Etc/GMT+11"),
+    ("Hawaiian Standard Time", "Pacific/Honolulu"),
+    ("Alaskan Standard Time", "America/Anchorage"),
+    ("Pacific Standard Time", "America/Los_Angeles"),
+    ("US Mountain Standard Time", "America/Phoenix"),
+    ("Mountain Standard Time", "America/Denver"),
+    ("Central America Standard Time", "America/Guatemala"),
+    ("Central Standard Time", "America/Chicago"),
+    ("Central Standard Time (Mexico)", "America/Mexico_City"),
+    ("Canada Central Standard Time", "America/Regina"),
+    ("SA Pacific Standard Time", "America/Bogota"),
+    ("Eastern Standard Time", "America/New_York"),
+    ("US Eastern Standard Time", "America/Indiana/Indianapolis"),
+    ("Venezuela Standard Time", "America/Caracas"),
+    ("Atlantic Standard Time", "America/Halifax"),
+    ("SA Western Standard Time", "America/La_Paz"),
+    ("Pacific SA Standard Time", "America/Santiago"),
+    ("Newfoundland Standard Time", "America/St_Johns"),
+    ("E. South America Standard Time", "America/Sao_Paulo"),
+    ("Argentina Standard Time", "America/Argentina/Buenos_Aires"),
+    ("SA Eastern Standard Time", "America/Cayenne"),
+    ("UTC-02", "Etc/GMT+2"),
+    ("Azores Standard Time", "Atlantic/Azores"),
+    ("Cape Verde Standard Time", "Atlantic/Cape_Verde"),
+    ("UTC", "Etc/UTC"),
+    ("GMT Standard Time", "Europe/London"),
+    ("Greenwich Standard Time", "Atlantic/Reykjavik"),
+    ("W. Europe Standard Time", "Europe/Berlin"),
+    ("Central Europe Standard Time", "Europe/Budapest"),
+    ("Romance Standard Time", "Europe/Paris"),
+    ("Central European Standard Time", "Europe/Warsaw"),
+    ("W. Central Africa Standard Time", "Africa/Lagos"),
+    ("GTB Standard Time", "Europe/Bucharest"),
+    ("Middle East Standard Time", "Asia/Beirut"),
+    ("Egypt Standard Time", "Africa/Cairo"),
+    ("E. Europe Standard Time", "Europe/Chisinau"),
+    ("South Africa Standard Time", "Africa/Johannesburg"),
+    ("FLE Standard Time", "Europe/Kyiv"),
+    ("Israel Standard Time", "Asia/Jerusalem"),
+    ("Turkey Standard Time", "Europe/Istanbul"),
+    ("Arabic Standard Time", "Asia/Baghdad"),
+    ("Arab Standard Time", "Asia/Riyadh"),
+    ("Russian Standard Time", "Europe/Moscow"),
+    ("E. Africa Standard Time", "Africa/Nairobi"),
+    ("Iran Standard Time", "Asia/Tehran"),
+    ("Arabian Standard Time", "Asia/Dubai"),
+    ("Azerbaijan Standard Time", "Asia/Baku"),
+    ("Afghanistan Standard Time", "Asia/Kabul"),
+    ("Pakistan Standard Time", "Asia/Karachi"),
+    ("West Asia Standard Time", "Asia/Tashkent"),
+    ("India Standard Time", "Asia/Kolkata"),
+    ("Sri Lanka Standard Time", "Asia/Colombo"),
+    ("Nepal Standard Time", "Asia/Kathmandu"),
+    ("Central Asia Standard Time", "Asia/Almaty"),
+    ("Bangladesh Standard Time", "Asia/Dhaka"),
+    ("Myanmar Standard Time", "Asia/Yangon"),
+    ("SE Asia Standard Time", "Asia/Bangkok"),
+    ("North Asia Standard Time", "Asia/Krasnoyarsk"),
+    ("China Standard Time", "Asia/Shanghai"),
+    ("Singapore Standard Time", "Asia/Singapore"),
+    ("W. Australia Standard Time", "Australia/Perth"),
+    ("Taipei Standard Time", "Asia/Taipei"),
+    ("Tokyo Standard Time", "Asia/Tokyo"),
+    ("Korea Standard Time", "Asia/Seoul"),
+    ("Cen. Australia Standard Time", "Australia/Adelaide"),
+    ("AUS Central Standard Time", "Australia/Darwin"),
+    ("E. Australia Standard Time", "Australia/Brisbane"),
+    ("AUS Eastern Standard Time", "Australia/Sydney"),
+    ("Tasmania Standard Time", "Australia/Hobart"),
+    ("West Pacific Standard Time", "Pacific/Port_Moresby"),
+    ("Vladivostok Standard Time", "Asia/Vladivostok"),
+    ("New Zealand Standard Time", "Pacific/Auckland"),
+    ("Fiji Standard Time", "Pacific/Fiji"),
+    ("Tonga Standard Time", "Pacific/Tongatapu"),
+    ("Samoa Standard Time", "Pacific/Apia"),
+    ("Line Islands Standard Time", "Pacific/Kiritimati"),
+];
+
+/// Where an alias was found
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AliasKind {
+    Abbreviation,
+    Windows,
+}
+
+/// IANA zones an alias may stand for, matched case-insensitively; more than one means the alias
+/// is ambiguous
+pub(crate) fn lookup(name: &str) -> Option<(AliasKind, &'static [&'static str])> {
+    let name = name.trim();
+    if let Some((_, zones)) = ABBREVIATIONS
+        .iter()
+        .find(|(abbreviation, _)| abbreviation.eq_ignore_ascii_case(name))
+    {
+        return Some((AliasKind::Abbreviation, zones));
+    }
+    WINDOWS_ZONES
+        .iter()
+        .find(|(windows, _)| windows.eq_ignore_ascii_case(name))
+        .map(|(_, zone)| (AliasKind::Windows, std::slice::from_ref(zone)))
+}
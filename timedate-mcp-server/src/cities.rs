@@ -0,0 +1,81 @@
+//! Embedded table of major cities with coordinates and IANA timezone
+
+pub(crate) struct City {
+    pub name: &'static str,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub timezone: &'static str,
+}
+
+const fn city(name: &'static str, latitude: f64, longitude: f64, timezone: &'static str) -> City {
+    City {
+        name,
+        latitude,
+        longitude,
+        timezone,
+    }
+}
+
+pub(crate) const CITIES: &[City] = &[
+    city("Amsterdam", 52.3676, 4.9041, "Europe/Amsterdam"),
+    city("Athens", 37.9838, 23.7275, "Europe/Athens"),
+    city("Auckland", -36.8485, 174.7633, "Pacific/Auckland"),
+    city("Bangkok", 13.7563, 100.5018, "Asia/Bangkok"),
+    city("Beijing", 39.9042, 116.4074, "Asia/Shanghai"),
+    city("Berlin", 52.5200, 13.4050, "Europe/Berlin"),
+    city(
+        "Buenos Aires",
+        -34.6037,
+        -58.3816,
+        "America/Argentina/Buenos_Aires",
+    ),
+    city("Cairo", 30.0444, 31.2357, "Africa/Cairo"),
+    city("Cape Town", -33.9249, 18.4241, "Africa/Johannesburg"),
+    city("Chicago", 41.8781, -87.6298, "America/Chicago"),
+    city("Delhi", 28.6139, 77.2090, "Asia/Kolkata"),
+    city("Denver", 39.7392, -104.9903, "America/Denver"),
+    city("Dubai", 25.2048, 55.2708, "Asia/Dubai"),
+    city("Dublin", 53.3498, -6.2603, "Europe/Dublin"),
+    city("Helsinki", 60.1699, 24.9384, "Europe/Helsinki"),
+    city("Hong Kong", 22.3193, 114.1694, "Asia/Hong_Kong"),
+    city("Honolulu", 21.3069, -157.8583, "Pacific/Honolulu"),
+    city("Istanbul", 41.0082, 28.9784, "Europe/Istanbul"),
+    city("Jakarta", -6.2088, 106.8456, "Asia/Jakarta"),
+    city("Johannesburg", -26.2041, 28.0473, "Africa/Johannesburg"),
+    city("Lagos", 6.5244, 3.3792, "Africa/Lagos"),
+    city("Lisbon", 38.7223, -9.1393, "Europe/Lisbon"),
+    city("London", 51.5074, -0.1278, "Europe/London"),
+    city("Los Angeles", 34.0522, -118.2437, "America/Los_Angeles"),
+    city("Madrid", 40.4168, -3.7038, "Europe/Madrid"),
+    city("Mexico City", 19.4326, -99.1332, "America/Mexico_City"),
+    city("Moscow", 55.7558, 37.6173, "Europe/Moscow"),
+    city("Mumbai", 19.0760, 72.8777, "Asia/Kolkata"),
+    city("Nairobi", -1.2921, 36.8219, "Africa/Nairobi"),
+    city("New York", 40.7128, -74.0060, "America/New_York"),
+    city("Oslo", 59.9139, 10.7522, "Europe/Oslo"),
+    city("Paris", 48.8566, 2.3522, "Europe/Paris"),
+    city("Reykjavik", 64.1466, -21.9426, "Atlantic/Reykjavik"),
+    city("Rome", 41.9028, 12.4964, "Europe/Rome"),
+    city("San Francisco", 37.7749, -122.4194, "America/Los_Angeles"),
+    city("Santiago", -33.4489, -70.6693, "America/Santiago"),
+    city("Sao Paulo", -23.5505, -46.6333, "America/Sao_Paulo"),
+    city("Seoul", 37.5665, 126.9780, "Asia/Seoul"),
+    city("Shanghai", 31.2304, 121.4737, "Asia/Shanghai"),
+    city("Singapore", 1.3521, 103.8198, "Asia/Singapore"),
+    city("Stockholm", 59.3293, 18.0686, "Europe/Stockholm"),
+    city("Sydney", -33.8688, 151.2093, "Australia/Sydney"),
+    city("Tokyo", 35.6762, 139.6503, "Asia/Tokyo"),
+    city("Toronto", 43.6532, -79.3832, "America/Toronto"),
+    city("Vancouver", 49.2827, -123.1207, "America/Vancouver"),
+    city("Vienna", 48.2082, 16.3738, "Europe/Vienna"),
+    city("Warsaw", 52.2297, 21.0122, "Europe/Warsaw"),
+    city("Zurich", 47.3769, 8.5417, "Europe/Zurich"),
+];
+
+/// Look up a city by name, ignoring case and treating underscores as spaces
+pub(crate) fn find_city(name: &str) -> Option<&'static City> {
+    let wanted = name.trim().replace('_', " ");
+    CITIES
+        .iter()
+        .find(|city| city.name.eq_ignore_ascii_case(&wanted))
+}
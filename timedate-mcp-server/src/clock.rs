@@ -0,0 +1,31 @@
+//! Source of the current instant used wherever a tool needs "now"
+//!
+//! The server reads the system clock by default; tests and embedders can pin a fixed instant
+//! with `TimeDateServer::with_clock(FixedClock(...))` to get deterministic results.
+
+use chrono::{DateTime, Utc};
+
+/// Provides the current instant
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Reads the operating system clock
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Always reports the same instant
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
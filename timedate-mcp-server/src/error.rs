@@ -0,0 +1,108 @@
+//! Typed failures shared by the tools
+//!
+//! Tools still return `anyhow::Result` because that is what the MCP macros render, but the
+//! failures below are raised as `TimeDateError` so callers can tell them apart with
+//! `err.downcast_ref::<TimeDateError>()` and a stable [`TimeDateError::code`]. Length, count,
+//! span and year bounds are checked in `limits` and raised as [`TimeDateError::InvalidInput`];
+//! other argument validation failures stay plain messages.
+
+use std::fmt::Display;
+
+#[derive(Debug, thiserror::Error)]
+pub enum TimeDateError {
+    /// A timezone name that is not in the IANA database, with the closest known names
+    #[error("Invalid {label}: {input}{}", did_you_mean(.suggestions))]
+    InvalidTimezone {
+        /// What the name was given as, e.g. "timezone" or "source timezone"
+        label: &'static str,
+        input: String,
+        suggestions: Vec<String>,
+    },
+
+    /// An alias such as "CST" that stands for several zones, with the zones it may mean
+    #[error("Ambiguous {label}: {input} (could be {})", .candidates.join(", "))]
+    AmbiguousTimezone {
+        label: &'static str,
+        input: String,
+        candidates: Vec<String>,
+    },
+
+    /// An argument outside the guardrails in `limits`: too long, containing control characters,
+    /// or a number, list length or span above its bound
+    #[error("Invalid {argument}: {reason}")]
+    InvalidInput {
+        argument: &'static str,
+        reason: String,
+    },
+
+    /// A date or time that matches none of the accepted formats
+    #[error("Invalid date format: {input}{}", expected_suffix(.expected))]
+    InvalidDateFormat {
+        input: String,
+        expected: Option<&'static str>,
+    },
+
+    /// A value that parses but lies outside what chrono can represent
+    #[error("{quantity} out of range: {input}")]
+    OutOfRange {
+        /// What overflowed, e.g. "Date" or "Year"
+        quantity: &'static str,
+        input: String,
+    },
+
+    /// A wall-clock time that occurs twice because clocks fall back
+    #[error("Local time {input} is ambiguous in {timezone}: it occurs at {earliest} and {latest}")]
+    AmbiguousLocalTime {
+        input: String,
+        timezone: String,
+        earliest: String,
+        latest: String,
+    },
+
+    /// A wall-clock time that is skipped because clocks spring forward
+    #[error("Local time {input} does not exist in {timezone} (skipped by a DST transition)")]
+    NonexistentLocalTime { input: String, timezone: String },
+}
+
+impl TimeDateError {
+    /// Stable machine-readable identifier of the variant
+    pub fn code(&self) -> &'static str {
+        match self {
+            TimeDateError::InvalidTimezone { .. } => "invalid_timezone",
+            TimeDateError::AmbiguousTimezone { .. } => "ambiguous_timezone",
+            TimeDateError::InvalidInput { .. } => "invalid_input",
+            TimeDateError::InvalidDateFormat { .. } => "invalid_date_format",
+            TimeDateError::OutOfRange { .. } => "out_of_range",
+            TimeDateError::AmbiguousLocalTime { .. } => "ambiguous_local_time",
+            TimeDateError::NonexistentLocalTime { .. } => "nonexistent_local_time",
+        }
+    }
+
+    pub(crate) fn invalid_date_format(input: impl Display) -> Self {
+        TimeDateError::InvalidDateFormat {
+            input: input.to_string(),
+            expected: None,
+        }
+    }
+
+    pub(crate) fn out_of_range(quantity: &'static str, input: impl Display) -> Self {
+        TimeDateError::OutOfRange {
+            quantity,
+            input: input.to_string(),
+        }
+    }
+}
+
+fn did_you_mean(suggestions: &[String]) -> String {
+    if suggestions.is_empty() {
+        String::new()
+    } else {
+        format!(" (did you mean {}?)", suggestions.join(", "))
+    }
+}
+
+fn expected_suffix(expected: &Option<&'static str>) -> String {
+    expected
+        .map(|expected| format!(" (expected {expected})"))
+        .unwrap_or_default()
+}
@@ -0,0 +1,199 @@
+//! Embedded table of national public holidays for a few countries, keyed by ISO 3166-1 alpha-2 code
+//!
+//! Dates are the nominal holiday dates. Substitute days observed when a holiday falls on a weekend,
+//! and regional holidays, are not included.
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+enum Rule {
+    /// Same month and day every year
+    Fixed(u32, u32),
+    /// The nth weekday of a month, or the last one when n is -1
+    NthWeekday(u32, Weekday, i32),
+    /// The last given weekday on or before a month and day
+    WeekdayOnOrBefore(u32, u32, Weekday),
+    /// Days relative to Western (Gregorian) Easter Sunday
+    Easter(i64),
+}
+
+struct Holiday {
+    name: &'static str,
+    rule: Rule,
+    /// First year the holiday was observed, when it is recent
+    since: Option<i32>,
+}
+
+const fn holiday(name: &'static str, rule: Rule) -> Holiday {
+    Holiday {
+        name,
+        rule,
+        since: None,
+    }
+}
+
+const fn holiday_since(name: &'static str, rule: Rule, since: i32) -> Holiday {
+    Holiday {
+        name,
+        rule,
+        since: Some(since),
+    }
+}
+
+const AU: &[Holiday] = &[
+    holiday("New Year's Day", Rule::Fixed(1, 1)),
+    holiday("Australia Day", Rule::Fixed(1, 26)),
+    holiday("Good Friday", Rule::Easter(-2)),
+    holiday("Easter Monday", Rule::Easter(1)),
+    holiday("Anzac Day", Rule::Fixed(4, 25)),
+    holiday("Christmas Day", Rule::Fixed(12, 25)),
+    holiday("Boxing Day", Rule::Fixed(12, 26)),
+];
+
+const CA: &[Holiday] = &[
+    holiday("New Year's Day", Rule::Fixed(1, 1)),
+    holiday("Good Friday", Rule::Easter(-2)),
+    holiday("Victoria Day", Rule::WeekdayOnOrBefore(5, 24, Weekday::Mon)),
+    holiday("Canada Day", Rule::Fixed(7, 1)),
+    holiday("Labour Day", Rule::NthWeekday(9, Weekday::Mon, 1)),
+    holiday_since(
+        "National Day for Truth and Reconciliation",
+        Rule::Fixed(9, 30),
+        2021,
+    ),
+    holiday("Thanksgiving", Rule::NthWeekday(10, Weekday::Mon, 2)),
+    holiday("Remembrance Day", Rule::Fixed(11, 11)),
+    holiday("Christmas Day", Rule::Fixed(12, 25)),
+    holiday("Boxing Day", Rule::Fixed(12, 26)),
+];
+
+const DE: &[Holiday] = &[
+    holiday("New Year's Day", Rule::Fixed(1, 1)),
+    holiday("Good Friday", Rule::Easter(-2)),
+    holiday("Easter Monday", Rule::Easter(1)),
+    holiday("Labour Day", Rule::Fixed(5, 1)),
+    holiday("Ascension Day", Rule::Easter(39)),
+    holiday("Whit Monday", Rule::Easter(50)),
+    holiday_since("German Unity Day", Rule::Fixed(10, 3), 1990),
+    holiday("Christmas Day", Rule::Fixed(12, 25)),
+    holiday("Second Day of Christmas", Rule::Fixed(12, 26)),
+];
+
+const FR: &[Holiday] = &[
+    holiday("New Year's Day", Rule::Fixed(1, 1)),
+    holiday("Easter Monday", Rule::Easter(1)),
+    holiday("Labour Day", Rule::Fixed(5, 1)),
+    holiday("Victory in Europe Day", Rule::Fixed(5, 8)),
+    holiday("Ascension Day", Rule::Easter(39)),
+    holiday("Whit Monday", Rule::Easter(50)),
+    holiday("Bastille Day", Rule::Fixed(7, 14)),
+    holiday("Assumption of Mary", Rule::Fixed(8, 15)),
+    holiday("All Saints' Day", Rule::Fixed(11, 1)),
+    holiday("Armistice Day", Rule::Fixed(11, 11)),
+    holiday("Christmas Day", Rule::Fixed(12, 25)),
+];
+
+/// England and Wales bank holidays
+const GB: &[Holiday] = &[
+    holiday("New Year's Day", Rule::Fixed(1, 1)),
+    holiday("Good Friday", Rule::Easter(-2)),
+    holiday("Easter Monday", Rule::Easter(1)),
+    holiday(
+        "Early May Bank Holiday",
+        Rule::NthWeekday(5, Weekday::Mon, 1),
+    ),
+    holiday("Spring Bank Holiday", Rule::NthWeekday(5, Weekday::Mon, -1)),
+    holiday("Summer Bank Holiday", Rule::NthWeekday(8, Weekday::Mon, -1)),
+    holiday("Christmas Day", Rule::Fixed(12, 25)),
+    holiday("Boxing Day", Rule::Fixed(12, 26)),
+];
+
+/// Federal holidays
+const US: &[Holiday] = &[
+    holiday("New Year's Day", Rule::Fixed(1, 1)),
+    holiday(
+        "Martin Luther King Jr. Day",
+        Rule::NthWeekday(1, Weekday::Mon, 3),
+    ),
+    holiday("Presidents' Day", Rule::NthWeekday(2, Weekday::Mon, 3)),
+    holiday("Memorial Day", Rule::NthWeekday(5, Weekday::Mon, -1)),
+    holiday_since("Juneteenth", Rule::Fixed(6, 19), 2021),
+    holiday("Independence Day", Rule::Fixed(7, 4)),
+    holiday("Labor Day", Rule::NthWeekday(9, Weekday::Mon, 1)),
+    holiday("Columbus Day", Rule::NthWeekday(10, Weekday::Mon, 2)),
+    holiday("Veterans Day", Rule::Fixed(11, 11)),
+    holiday("Thanksgiving Day", Rule::NthWeekday(11, Weekday::Thu, 4)),
+    holiday("Christmas Day", Rule::Fixed(12, 25)),
+];
+
+pub(crate) const COUNTRY_CODES: &[&str] = &["AU", "CA", "DE", "FR", "GB", "US"];
+
+fn table(country_code: &str) -> Option<&'static [Holiday]> {
+    match country_code.trim().to_ascii_uppercase().as_str() {
+        "AU" => Some(AU),
+        "CA" => Some(CA),
+        "DE" => Some(DE),
+        "FR" => Some(FR),
+        "GB" | "UK" => Some(GB),
+        "US" => Some(US),
+        _ => None,
+    }
+}
+
+/// Western Easter Sunday (anonymous Gregorian algorithm)
+fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+fn resolve(rule: &Rule, year: i32) -> Option<NaiveDate> {
+    match *rule {
+        Rule::Fixed(month, day) => NaiveDate::from_ymd_opt(year, month, day),
+        Rule::NthWeekday(month, weekday, n) if n > 0 => {
+            NaiveDate::from_weekday_of_month_opt(year, month, weekday, n as u8)
+        }
+        Rule::NthWeekday(month, weekday, _) => {
+            let first_of_next = if month == 12 {
+                NaiveDate::from_ymd_opt(year + 1, 1, 1)
+            } else {
+                NaiveDate::from_ymd_opt(year, month + 1, 1)
+            }?;
+            let last = first_of_next.pred_opt()?;
+            let back =
+                (7 + last.weekday().num_days_from_monday() - weekday.num_days_from_monday()) % 7;
+            last.checked_sub_days(chrono::Days::new(u64::from(back)))
+        }
+        Rule::WeekdayOnOrBefore(month, day, weekday) => {
+            let date = NaiveDate::from_ymd_opt(year, month, day)?;
+            let back =
+                (7 + date.weekday().num_days_from_monday() - weekday.num_days_from_monday()) % 7;
+            date.checked_sub_days(chrono::Days::new(u64::from(back)))
+        }
+        Rule::Easter(offset) => {
+            easter_sunday(year)?.checked_add_signed(chrono::Duration::days(offset))
+        }
+    }
+}
+
+/// Public holidays of a country in a year, sorted by date, or None for an unknown country code
+pub(crate) fn holidays_in(country_code: &str, year: i32) -> Option<Vec<(NaiveDate, &'static str)>> {
+    let mut holidays: Vec<(NaiveDate, &'static str)> = table(country_code)?
+        .iter()
+        .filter(|holiday| holiday.since.is_none_or(|since| year >= since))
+        .filter_map(|holiday| resolve(&holiday.rule, year).map(|date| (date, holiday.name)))
+        .collect();
+    holidays.sort();
+    Some(holidays)
+}
@@ -1,11 +1,123 @@
 //! TimeDate MCP Server - Time and Date Operations with Timezone Support
 
-use chrono::{DateTime, Local, TimeZone, Utc};
-use chrono_tz::{Tz, TZ_VARIANTS};
+use chrono::{
+    DateTime, Datelike, Local, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset,
+    SecondsFormat, TimeZone, Timelike, Utc, Weekday,
+};
+use chrono_tz::{OffsetComponents, OffsetName, Tz, TZ_VARIANTS};
 use pulseengine_mcp_macros::{mcp_resource, mcp_server, mcp_tools};
+use pulseengine_mcp_protocol::{CallToolRequestParam, Content};
+use pulseengine_mcp_server::McpToolsProvider;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+use std::future::Future;
+use std::pin::Pin;
 use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
+
+mod abbreviations;
+mod aliases;
+mod cities;
+mod clock;
+mod error;
+mod holidays;
+mod limits;
+mod locales;
+mod ntp;
+mod solar;
+
+pub use clock::{Clock, FixedClock, SystemClock};
+pub use error::TimeDateError;
+use limits::{MAX_CRON_OCCURRENCES, MAX_HORIZON_DAYS, MAX_SERIES_LEN};
+
+/// Formats tried before any strftime pattern, as (name, pattern, example). RFC3339 and RFC2822
+/// are parsed leniently beyond the listed pattern.
+const BUILTIN_DATE_TIME_FORMATS: &[(&str, &str, &str)] = &[
+    ("now", "now", "now"),
+    (
+        "rfc3339",
+        "%Y-%m-%dT%H:%M:%S%.f%:z",
+        "2024-01-15T14:30:00.5Z",
+    ),
+    (
+        "rfc2822",
+        "%a, %d %b %Y %H:%M:%S %z",
+        "Mon, 15 Jan 2024 14:30:00 +0100",
+    ),
+];
+
+/// Formats with an explicit UTC offset, tried after RFC3339 and RFC2822, as (name, pattern, example)
+const OFFSET_DATE_TIME_FORMATS: &[(&str, &str, &str)] = &[
+    (
+        "offset_colon",
+        "%Y-%m-%d %H:%M:%S%.f%:z",
+        "2024-01-15 14:30:00+05:30",
+    ),
+    (
+        "offset_colon_spaced",
+        "%Y-%m-%d %H:%M:%S%.f %:z",
+        "2024-01-15 14:30:00 +05:30",
+    ),
+    (
+        "offset_compact",
+        "%Y-%m-%d %H:%M:%S%.f%z",
+        "2024-01-15 14:30:00+0530",
+    ),
+    (
+        "offset_compact_spaced",
+        "%Y-%m-%d %H:%M:%S%.f %z",
+        "2024-01-15 14:30:00 +0530",
+    ),
+    (
+        "iso_offset_compact",
+        "%Y-%m-%dT%H:%M:%S%.f%z",
+        "2024-01-15T14:30:00+0530",
+    ),
+];
+
+/// Naive date-time formats, read in the caller's timezone. `%.f` also matches no fraction.
+const NAIVE_DATE_TIME_FORMATS: &[(&str, &str, &str)] = &[
+    ("iso_local", "%Y-%m-%dT%H:%M:%S%.f", "2024-01-15T14:30:00"),
+    ("local", "%Y-%m-%d %H:%M:%S%.f", "2024-01-15 14:30:00.250"),
+    ("iso_local_minutes", "%Y-%m-%dT%H:%M", "2024-01-15T14:30"),
+    ("local_minutes", "%Y-%m-%d %H:%M", "2024-01-15 14:30"),
+    (
+        "slashed_local",
+        "%Y/%m/%d %H:%M:%S%.f",
+        "2024/01/15 14:30:00",
+    ),
+    (
+        "slashed_local_minutes",
+        "%Y/%m/%d %H:%M",
+        "2024/01/15 14:30",
+    ),
+    ("us_local", "%m/%d/%Y %H:%M:%S%.f", "01/15/2024 14:30:00"),
+    ("us_local_minutes", "%m/%d/%Y %H:%M", "01/15/2024 14:30"),
+];
+
+/// Naive date formats, read as local midnight. Slashed dates with the year last are month-first (US style).
+const NAIVE_DATE_FORMATS: &[(&str, &str, &str)] = &[
+    ("date", "%Y-%m-%d", "2024-01-15"),
+    ("slashed_date", "%Y/%m/%d", "2024/01/15"),
+    ("us_date", "%m/%d/%Y", "01/15/2024"),
+];
+
+/// How to resolve a naive local time that occurs twice when clocks fall back
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Disambiguation {
+    Earliest,
+    Latest,
+    Reject,
+}
+
+/// Julian Day Number of 1970-01-01
+const UNIX_EPOCH_JDN: i64 = 2_440_588;
+/// Julian Day Number of 1858-11-17, where Modified Julian Dates start
+const MJD_EPOCH_JDN: i64 = 2_400_001;
+
+/// Environment variable naming the server's default timezone, read by `TimeDateServer::from_env`
+pub const DEFAULT_TIMEZONE_ENV: &str = "TIMEDATE_DEFAULT_TZ";
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct TimeInfo {
@@ -17,251 +129,5891 @@ pub struct TimeInfo {
     pub format_24h: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
-pub struct TimezoneInfo {
-    pub name: String,
-    pub current_time: String,
-    pub utc_offset: String,
-    pub is_dst: bool,
-}
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TimezoneInfo {
+    pub name: String,
+    pub current_time: String,
+    pub utc_offset: String,
+    pub is_dst: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TimeFormatInfo {
+    pub detected_format: String,
+    pub is_12_hour: bool,
+    pub current_time_12h: String,
+    pub current_time_24h: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct VersionAge {
+    pub version: String,
+    pub release_date: String,
+    pub age_days: i64,
+    pub age_seconds: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SunTimes {
+    pub date: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub timezone: String,
+    /// "normal", "polar_day" (sun never sets) or "polar_night" (sun never rises)
+    pub status: String,
+    pub sunrise: Option<String>,
+    pub sunset: Option<String>,
+    pub solar_noon: String,
+    pub day_length_seconds: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FlightTimes {
+    pub departure: String,
+    pub arrival: TimeInfo,
+    /// How far the wall clock moves between departure and arrival, in minutes
+    pub net_clock_change_minutes: i64,
+    /// Arrival zone offset minus departure zone offset (positive when flying east)
+    pub jet_lag_hours: f64,
+    pub zones_crossed: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct HolidayWeek {
+    pub date: String,
+    pub weekday: String,
+    pub iso_year: i32,
+    pub iso_week: u32,
+    pub week_start: String,
+    pub week_end: String,
+    /// True when the holiday falls on a Monday or Friday, extending the weekend
+    pub long_weekend: bool,
+}
+
+/// A weekly recurring event at a local clock time in a timezone
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RecurrenceSpec {
+    /// Weekday name, e.g. "monday"
+    pub weekday: String,
+    /// Local start time, e.g. "09:30"
+    pub time: String,
+    pub timezone: String,
+    pub duration_minutes: u32,
+    /// First date the recurrence applies ("YYYY-MM-DD"), defaults to today
+    pub starts_on: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(inline)]
+pub struct Interval {
+    pub start: String,
+    pub end: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct WeekAnchors {
+    pub date: String,
+    pub week_start: String,
+    pub first_day: String,
+    pub last_day: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SeasonBounds {
+    pub year: i32,
+    pub hemisphere: String,
+    pub spring_start: String,
+    pub summer_start: String,
+    pub autumn_start: String,
+    pub winter_start: String,
+}
+
+/// A signed duration with a day/hour/minute/second breakdown of its magnitude
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct DurationInfo {
+    pub total_seconds: i64,
+    pub is_negative: bool,
+    pub days: i64,
+    pub hours: i64,
+    pub minutes: i64,
+    pub seconds: i64,
+    /// ISO 8601 duration such as "P1DT2H3M" (prefixed with "-" when negative)
+    pub iso8601: String,
+    pub human_readable: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ZoneYearSummary {
+    pub timezone: String,
+    pub year: i32,
+    pub standard_offset: String,
+    pub dst_offset: Option<String>,
+    pub transitions: usize,
+    pub abbreviations: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct WeekendCount {
+    pub year: i32,
+    pub month: u32,
+    pub weekend_days: u32,
+    pub full_weekends: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct Components {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+    pub nanosecond: u32,
+    pub weekday: String,
+    pub offset_seconds: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ZoneHour {
+    pub timezone: String,
+    pub local_time: String,
+    pub local_hour: u32,
+    pub is_working_hours: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct HalfOfYear {
+    pub year: i32,
+    pub half: u32,
+    pub first_day: String,
+    pub last_day: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ServiceInfo {
+    pub start_date: String,
+    pub completed_years: i32,
+    pub next_anniversary: String,
+    pub days_until_next: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ClosestZone {
+    pub timezone: String,
+    pub local_time: String,
+    pub minutes_from_target: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ClockReading {
+    pub time_12h: String,
+    pub time_24h: String,
+    pub date: String,
+    pub weekday: String,
+    pub dst_label: String,
+    pub abbreviation: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct UnixTimestamp {
+    pub seconds: i64,
+    pub milliseconds: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct JulianDate {
+    /// Julian Day Number of the UTC calendar date, e.g. 2451545 for 2000-01-01
+    pub julian_day_number: i64,
+    /// Days since noon UTC on 4713-11-24 BC (proleptic Gregorian), with the time as a fraction
+    pub julian_date: f64,
+    /// `julian_date - 2400000.5`, so days start at midnight
+    pub modified_julian_date: f64,
+}
+
+/// List of intervals taken as a tool parameter. The tool macro cannot build a schema for
+/// `Vec` of a custom struct directly, but an alias falls back to the `JsonSchema` impl. Item
+/// structs are `#[schemars(inline)]` since the generated schema carries no definitions.
+pub type IntervalList = Vec<Interval>;
+
+/// One region's daily support shift, on its local clock Monday through Friday
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(inline)]
+pub struct SupportZone {
+    pub timezone: String,
+    pub day_start_hour: u32,
+    /// End of the shift (exclusive); 24 means midnight at the end of the day
+    pub day_end_hour: u32,
+}
+
+/// Support shifts taken as a tool parameter, see `IntervalList`
+pub type SupportZoneList = Vec<SupportZone>;
+
+/// A meeting participant's availability on their local clock
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[schemars(inline)]
+pub struct Participant {
+    pub timezone: String,
+    pub start_hour: u32,
+    /// End of the window (exclusive, up to 24); earlier than `start_hour` wraps past midnight
+    pub end_hour: u32,
+}
+
+/// Participants taken as a tool parameter, see `IntervalList`
+pub type ParticipantList = Vec<Participant>;
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CoverageInfo {
+    pub covered_seconds: i64,
+    pub uncovered_seconds: i64,
+    /// Covered share of the window, from 0.0 to 1.0
+    pub coverage_fraction: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct DayInfo {
+    pub date: String,
+    pub weekday: String,
+    /// ISO weekday number, Monday = 1 through Sunday = 7
+    pub iso_weekday: u32,
+    pub day_of_month: u32,
+    pub day_of_year: u32,
+    pub iso_year: i32,
+    pub iso_week: u32,
+    pub quarter: u32,
+    pub is_weekend: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FormatSpec {
+    pub name: String,
+    /// strftime pattern; RFC3339 and RFC2822 are parsed leniently beyond this pattern
+    pub pattern: String,
+    pub example: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SmartDiff {
+    /// Difference in `unit`, rounded to two decimals (negative when end is before start)
+    pub value: f64,
+    pub unit: String,
+    pub humanized: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct DstTransition {
+    /// "spring_forward" when clocks move ahead, "fall_back" when they move back
+    pub kind: String,
+    /// Instant of the change in UTC (RFC3339)
+    pub instant: String,
+    pub offset_before: String,
+    pub offset_after: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TimezoneDetails {
+    /// IANA name, e.g. "Europe/Berlin"
+    pub name: String,
+    /// Current abbreviation, e.g. "CEST", or the numeric offset for zones without one
+    pub abbreviation: String,
+    /// Offset in effect now
+    pub utc_offset: String,
+    /// Offset outside daylight saving time
+    pub standard_offset: String,
+    /// Offset during daylight saving time. Zones with negative DST in the tz database, such as
+    /// Europe/Dublin, report their winter offset here.
+    pub dst_offset: Option<String>,
+    pub is_dst: bool,
+    pub next_transition: Option<DstTransition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct JetLag {
+    /// Destination offset minus origin offset on the travel date, in hours
+    pub offset_difference_hours: f64,
+    /// "eastward", "westward" or "none"
+    pub direction: String,
+    /// Rough recovery estimate: one day per hour the body clock shifts, taking the shorter way
+    /// around the clock (so never more than 12)
+    pub adaptation_days: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ParseReport {
+    pub input: String,
+    pub matched: bool,
+    /// Name of the matching format, as listed by `supported_formats`
+    pub format: Option<String>,
+    pub pattern: Option<String>,
+    /// Whether the input carried its own UTC offset (otherwise it was read in `timezone`)
+    pub has_offset: bool,
+    pub utc: Option<String>,
+    pub local: Option<TimeInfo>,
+    /// Why parsing failed, when a format matched but the local time could not be resolved
+    pub error: Option<String>,
+    /// Names of the formats that were tried, filled in when nothing matched
+    pub attempted_formats: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TransitionCheck {
+    /// Whether the instant is exactly when the zone's clocks change
+    pub is_transition: bool,
+    /// Whether a clock change happens within an hour of the instant
+    pub near_transition: bool,
+    /// Closest clock change within a day of the instant (UTC, RFC3339)
+    pub nearest_transition: Option<String>,
+    /// Seconds from the nearest transition to the instant (negative when the instant is before it)
+    pub seconds_from_transition: Option<i64>,
+    pub offset_before: Option<String>,
+    pub offset_after: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct DateTimeComparison {
+    /// "before", "equal" or "after", describing `a` relative to `b`
+    pub ordering: String,
+    /// `b` minus `a` in seconds (positive when `a` is before `b`)
+    pub difference_seconds: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct HolidayEntry {
+    pub date: String,
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct AnnotatedMonthGrid {
+    pub year: i32,
+    pub month: u32,
+    pub country_code: String,
+    /// Monday-first weeks; days outside the month are null
+    pub weeks: Vec<Vec<Option<u32>>>,
+    pub holidays: Vec<HolidayEntry>,
+    /// Saturdays and Sundays of the month as "YYYY-MM-DD"
+    pub weekends: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct RelativeTime {
+    /// Phrase such as "3 hours ago", "in 2 days" or "just now"
+    pub text: String,
+    /// Time from the reference to the date, in seconds (negative in the past)
+    pub seconds: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SolarExtremes {
+    pub year: i32,
+    pub timezone: String,
+    /// Sunrise with the earliest local clock time in the year (RFC3339), if the sun ever rises
+    pub earliest_sunrise: Option<String>,
+    pub latest_sunrise: Option<String>,
+    pub earliest_sunset: Option<String>,
+    pub latest_sunset: Option<String>,
+    /// Days on which the sun never sets
+    pub polar_days: u32,
+    /// Days on which the sun never rises
+    pub polar_nights: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ZoneClock {
+    /// Timezone as requested
+    pub timezone: String,
+    pub info: Option<TimezoneInfo>,
+    /// Why the entry could not be computed, e.g. an unknown timezone name
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct PrecisionInfo {
+    /// "date", "minute", "second", "millisecond", "microsecond" or "nanosecond"
+    pub precision: String,
+    /// Name of the matching input format, as listed by `supported_formats`
+    pub format: String,
+    /// Number of digits written after the decimal point of the seconds
+    pub fractional_digits: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct PeriodBounds {
+    pub period: String,
+    /// First instant of the period (inclusive), at local midnight
+    pub start: String,
+    /// First instant after the period (exclusive), at local midnight
+    pub end: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FiscalPeriod {
+    /// Named after the calendar year the fiscal year ends in, e.g. "FY2025" for Oct 2024 - Sep 2025
+    pub fiscal_year: String,
+    /// 1 to 4, counted from the fiscal year start
+    pub fiscal_quarter: u32,
+    /// First day of the fiscal quarter (YYYY-MM-DD)
+    pub quarter_start: String,
+    /// Last day of the fiscal quarter (inclusive)
+    pub quarter_end: String,
+    pub year_start: String,
+    /// Last day of the fiscal year (inclusive)
+    pub year_end: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct RegionGroup {
+    /// Continent prefix of the zone names, e.g. "America" or "Europe"
+    pub region: String,
+    /// UTC offset of the abbreviation in this region (e.g. "+01:00")
+    pub offset: String,
+    pub zone_count: usize,
+    /// Up to five zone names from the region, alphabetically
+    pub example_zones: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct AgeInfo {
+    pub birth_date: String,
+    pub as_of: String,
+    pub years: i32,
+    /// Whole months since the last birthday (0-11)
+    pub months: u32,
+    /// Days since the last whole month
+    pub days: i64,
+    pub total_days: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CronWindow {
+    /// First minute of the next run of consecutive firings
+    pub active_from: TimeInfo,
+    /// First minute after that run in which the expression does not fire
+    pub active_until: TimeInfo,
+    /// Firing that starts the run after this one
+    pub next_active_from: TimeInfo,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct OffsetGroup {
+    /// Current UTC offset shared by the zones (e.g. "+01:00")
+    pub offset: String,
+    pub timezones: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ZoneConversion {
+    /// Target timezone as requested
+    pub timezone: String,
+    pub time: Option<TimeInfo>,
+    /// Why the entry could not be converted, e.g. an unknown timezone name
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct OffsetDifference {
+    /// Minutes `to_timezone` is ahead of `from_timezone` (negative when behind)
+    pub minutes: i32,
+    /// e.g. "Tokyo is 7 hours ahead of Berlin"
+    pub description: String,
+}
+
+/// Clock or calendar unit, in lowercase singular form such as "hour"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl std::fmt::Display for TimeUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            TimeUnit::Second => "second",
+            TimeUnit::Minute => "minute",
+            TimeUnit::Hour => "hour",
+            TimeUnit::Day => "day",
+            TimeUnit::Week => "week",
+            TimeUnit::Month => "month",
+            TimeUnit::Year => "year",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Lenient parsing for units written in free text: any case, plurals and short forms ("3 hrs")
+impl FromStr for TimeUnit {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().trim_end_matches('s') {
+            "second" | "sec" => Ok(TimeUnit::Second),
+            "minute" | "min" => Ok(TimeUnit::Minute),
+            "hour" | "hr" => Ok(TimeUnit::Hour),
+            "day" => Ok(TimeUnit::Day),
+            "week" => Ok(TimeUnit::Week),
+            "month" => Ok(TimeUnit::Month),
+            "year" => Ok(TimeUnit::Year),
+            _ => Err(anyhow::anyhow!("Unrecognized time unit: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct Countdown {
+    /// Target instant (RFC3339)
+    pub target: String,
+    /// Time left until the target, all zero once it has passed
+    pub remaining: DurationInfo,
+    pub has_passed: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct NtpTime {
+    /// Network time when the reply arrived
+    pub time: TimeInfo,
+    pub server: String,
+    /// How far the network time is ahead of the local clock (negative when the clock runs fast)
+    pub offset_ms: i64,
+    pub round_trip_ms: i64,
+    /// Distance of the server from a reference clock (1 = directly attached)
+    pub stratum: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[schemars(inline)]
+pub struct BatchOperation {
+    /// Name of an existing tool, e.g. "convert_timezone"
+    pub operation: String,
+    /// The tool's arguments as a JSON object (none for tools without parameters)
+    pub arguments: Option<serde_json::Value>,
+}
+
+/// Batch operations taken as a tool parameter, see `IntervalList`. `batch` keeps a second
+/// parameter because the tool macro flattens a lone parameter's schema into the tool's own.
+pub type BatchOperationList = Vec<BatchOperation>;
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BatchResult {
+    pub operation: String,
+    /// The tool's output, rendered as it would be for a direct call
+    pub result: Option<String>,
+    /// Why the operation failed; other operations still run unless the batch stops on errors
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ScheduleCheck {
+    pub within_schedule: bool,
+    /// Start of the next window when outside the schedule, None when inside or when no weekday
+    /// is allowed
+    pub next_start: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ServerCapabilities {
+    pub version: String,
+    /// Zone used when a tool's optional timezone argument is omitted
+    pub default_timezone: String,
+    /// NTP server queried by `get_ntp_time`
+    pub ntp_server: String,
+    /// Input formats the shared parser accepts, in the order they are tried
+    pub input_formats: Vec<FormatSpec>,
+    pub tools: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CalendarFacts {
+    pub year: i32,
+    pub is_leap_year: bool,
+    pub days_in_year: u32,
+    /// Days in January through December
+    pub days_in_month: Vec<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ResolvedTimezone {
+    pub input: String,
+    /// Canonical IANA zone, None when the input is ambiguous
+    pub timezone: Option<String>,
+    /// Every zone the input may stand for; several for an ambiguous abbreviation such as "CST"
+    pub candidates: Vec<String>,
+    /// "iana", "abbreviation", "windows" or "offset"
+    pub source: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ClockTick {
+    pub now: TimeInfo,
+    /// Next interval boundary on the local clock, e.g. the next whole minute
+    pub next_tick: TimeInfo,
+    /// When to read the clock again to land on the boundary
+    pub millis_until_next_tick: i64,
+}
+
+/// Calendar or numbering system a date can be expressed in, in lowercase such as "julian"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Calendar {
+    /// Proleptic Julian calendar, e.g. "2026-10-01" for Gregorian 2026-10-14
+    Julian,
+    /// ISO 8601 week date, e.g. "2026-W42-3"
+    Iso,
+    /// Seconds since 1970-01-01T00:00:00Z
+    Unix,
+}
+
+impl std::fmt::Display for Calendar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Calendar::Julian => "julian",
+            Calendar::Iso => "iso",
+            Calendar::Unix => "unix",
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct AlternateCalendarDate {
+    pub calendar: Calendar,
+    /// The date written in that calendar
+    pub value: String,
+    /// Local Gregorian date it was converted from
+    pub gregorian_date: String,
+}
+
+/// How much of a time the core time tools return
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Verbosity {
+    /// Only the RFC3339 timestamp
+    Minimal,
+    /// Every `TimeInfo` field
+    #[default]
+    Full,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MinimalTimeInfo {
+    /// RFC3339 timestamp in the requested timezone
+    pub timestamp: String,
+}
+
+/// A `TimeInfo` or its minimal form, rendered exactly like the value it holds
+#[derive(Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum TimeOutput {
+    Full(TimeInfo),
+    Minimal(MinimalTimeInfo),
+}
+
+impl std::fmt::Debug for TimeOutput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimeOutput::Full(info) => info.fmt(f),
+            TimeOutput::Minimal(info) => info.fmt(f),
+        }
+    }
+}
+
+/// TimeDate MCP Server - Time and Date Operations with Timezone Support
+#[mcp_server(
+    name = "TimeDate MCP Server",
+    version = "0.4.0",
+    description = "A Model Context Protocol server for time and date operations with timezone support with parameterized resources",
+    auth = "disabled"
+)]
+#[derive(Clone)]
+pub struct TimeDateServer {
+    clock: Arc<dyn Clock>,
+    /// Zone used when a tool's optional timezone argument is omitted
+    default_timezone: Tz,
+    /// "host" or "host:port" queried by `get_ntp_time`
+    ntp_server: String,
+}
+
+impl Default for TimeDateServer {
+    fn default() -> Self {
+        Self::with_clock(SystemClock)
+    }
+}
+
+impl TimeDateServer {
+    /// Create a server that reads "now" from a custom clock, e.g. a `FixedClock` in tests
+    pub fn with_clock(clock: impl Clock + 'static) -> Self {
+        Self {
+            clock: Arc::new(clock),
+            default_timezone: chrono_tz::UTC,
+            ntp_server: ntp::DEFAULT_NTP_SERVER.to_string(),
+        }
+    }
+
+    /// Query a different NTP server ("host" or "host:port", port 123 by default) in `get_ntp_time`
+    pub fn with_ntp_server(mut self, server: impl Into<String>) -> Self {
+        self.ntp_server = server.into();
+        self
+    }
+
+    /// Use a zone other than UTC whenever a tool's optional timezone argument is omitted
+    pub fn with_default_timezone(mut self, timezone: &str) -> anyhow::Result<Self> {
+        self.default_timezone = self.parse_timezone_as(timezone.trim(), "default timezone")?;
+        Ok(self)
+    }
+
+    /// Create a server whose default timezone comes from `TIMEDATE_DEFAULT_TZ`, falling back to
+    /// UTC when the variable is unset or empty. An unknown zone name is an error.
+    pub fn from_env() -> anyhow::Result<Self> {
+        let server = Self::default();
+        match std::env::var(DEFAULT_TIMEZONE_ENV) {
+            Ok(name) if !name.trim().is_empty() => server.with_default_timezone(&name),
+            _ => Ok(server),
+        }
+    }
+}
+
+#[mcp_tools]
+impl TimeDateServer {
+    /// Get time at a specific date and timezone
+    pub async fn get_time_at(
+        &self,
+        date_time: String,
+        timezone: Option<String>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+
+        let dt = self.parse_flexible_datetime(&date_time, &tz)?;
+
+        Ok(self.time_output(dt, verbosity))
+    }
+
+    /// Calculate time with offset (add/subtract time), given as whole hours and/or a
+    /// natural-language offset like "+3 days", "-45 minutes" or "2 weeks 3 hours"
+    pub async fn calculate_time_offset(
+        &self,
+        base_time: String,
+        offset_hours: Option<i32>,
+        offset: Option<String>,
+        timezone: Option<String>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        if offset_hours.is_none() && offset.is_none() {
+            return Err(anyhow::anyhow!(
+                "Either offset_hours or offset must be provided"
+            ));
+        }
+        let mut total = chrono::Duration::hours(i64::from(offset_hours.unwrap_or(0)));
+        if let Some(text) = &offset {
+            total = total
+                .checked_add(&self.parse_relative_offset(text)?)
+                .ok_or_else(|| TimeDateError::out_of_range("Offset", text))?;
+        }
+
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+
+        let base_dt = self.parse_flexible_datetime(&base_time, &tz)?;
+
+        // Results are held to the years the parsers accept, so they can be fed back in
+        let result_dt = base_dt
+            .checked_add_signed(total)
+            .filter(|dt| (limits::MIN_YEAR..=limits::MAX_YEAR).contains(&dt.year()))
+            .ok_or_else(|| TimeDateError::out_of_range("Resulting time", &base_time))?;
+        Ok(self.time_output(result_dt, verbosity))
+    }
+
+    /// Convert time between timezones. A local time repeated when clocks fall back resolves to its
+    /// earlier reading unless `disambiguate` asks for "latest" or "reject"
+    pub async fn convert_timezone(
+        &self,
+        time: String,
+        from_timezone: String,
+        to_timezone: String,
+        disambiguate: Option<String>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        let from_tz = self.parse_timezone_as(&from_timezone, "source timezone")?;
+        let to_tz = self.parse_timezone_as(&to_timezone, "target timezone")?;
+
+        let mode = self.parse_disambiguation(disambiguate.as_deref())?;
+        let dt = self.parse_flexible_datetime_with(&time, &from_tz, mode)?;
+
+        let converted = dt.with_timezone(&to_tz);
+        Ok(self.time_output(converted, verbosity))
+    }
+
+    /// Get current time in local timezone (exposed as a tool for now)
+    pub async fn get_current_time(
+        &self,
+        timezone: Option<String>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        Ok(self.time_output(self.now().with_timezone(&tz), verbosity))
+    }
+
+    /// Get the current time from the configured NTP server (pool.ntp.org by default) along with
+    /// the measured offset of the local clock. Fails after 5 seconds without an answer.
+    pub async fn get_ntp_time(&self, timezone: Option<String>) -> anyhow::Result<NtpTime> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let sample = ntp::query(&self.ntp_server, self.clock.as_ref()).await?;
+
+        Ok(NtpTime {
+            time: self.format_time_info(sample.server_time.with_timezone(&tz)),
+            server: self.ntp_server.clone(),
+            offset_ms: sample.offset.num_milliseconds(),
+            round_trip_ms: sample.round_trip.num_milliseconds(),
+            stratum: sample.stratum,
+        })
+    }
+
+    /// Get current timezone information
+    pub async fn get_timezone_info(&self) -> anyhow::Result<TimezoneInfo> {
+        self.get_timezone_info_internal().await
+    }
+
+    /// Get time format preference information, from an explicit locale (e.g. "en_AU") or the environment
+    pub async fn get_time_format(&self, locale: Option<String>) -> anyhow::Result<TimeFormatInfo> {
+        self.get_time_format_internal(locale).await
+    }
+
+    /// List available timezones, optionally filtered and limited (all matches by default)
+    pub async fn list_timezones(
+        &self,
+        filter: Option<String>,
+        limit: Option<usize>,
+    ) -> anyhow::Result<Vec<String>> {
+        self.list_timezones_internal(filter, limit).await
+    }
+
+    /// Get the signed number of hours zone B is ahead of zone A (fractional for half-hour zones)
+    pub async fn hours_apart(
+        &self,
+        tz_a: String,
+        tz_b: String,
+        at: Option<String>,
+    ) -> anyhow::Result<f64> {
+        let zone_a = self.parse_timezone(&tz_a)?;
+        let zone_b = self.parse_timezone(&tz_b)?;
+        let instant =
+            self.parse_flexible_datetime(at.as_deref().unwrap_or("now"), &chrono_tz::UTC)?;
+
+        let offset_a = self.offset_seconds_at(&zone_a, &instant);
+        let offset_b = self.offset_seconds_at(&zone_b, &instant);
+        Ok(f64::from(offset_b - offset_a) / 3600.0)
+    }
+
+    /// Render a time as UTC RFC3339 with a trailing "Z" instead of "+00:00"
+    pub async fn to_rfc3339_z(&self, date_time: String) -> anyhow::Result<String> {
+        let dt = self.parse_flexible_datetime(&date_time, &chrono_tz::UTC)?;
+        Ok(dt
+            .with_timezone(&Utc)
+            .to_rfc3339_opts(SecondsFormat::AutoSi, true))
+    }
+
+    /// Render a time as RFC3339 with an explicit numeric offset (e.g. "+00:00") in the given timezone
+    pub async fn to_rfc3339_offset(
+        &self,
+        date_time: String,
+        timezone: Option<String>,
+    ) -> anyhow::Result<String> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let dt = self.parse_flexible_datetime(&date_time, &tz)?;
+        Ok(dt.to_rfc3339_opts(SecondsFormat::AutoSi, false))
+    }
+
+    /// Infer 12-hour vs 24-hour preference from a user-entered time like "3:30 PM" or "15:30"
+    pub async fn infer_hour_format(&self, sample: String) -> anyhow::Result<TimeFormatInfo> {
+        let (time, has_meridiem) = self.parse_clock_sample(&sample)?;
+        let hour = time.hour();
+
+        // AM/PM markers are decisive; otherwise only hours outside 1-12 rule out a 12-hour clock
+        let detected_format = if has_meridiem {
+            "12-hour"
+        } else if hour == 0 || hour > 12 {
+            "24-hour"
+        } else {
+            "ambiguous"
+        };
+
+        Ok(TimeFormatInfo {
+            detected_format: detected_format.to_string(),
+            is_12_hour: has_meridiem,
+            current_time_12h: time.format("%I:%M:%S %p").to_string(),
+            current_time_24h: time.format("%H:%M:%S").to_string(),
+        })
+    }
+
+    /// List the most recent hour or day boundaries at or before a time, most recent first
+    pub async fn recent_boundaries(
+        &self,
+        unit: TimeUnit,
+        count: usize,
+        timezone: Option<String>,
+        from: Option<String>,
+    ) -> anyhow::Result<Vec<String>> {
+        limits::check_at_most("count", count, MAX_SERIES_LEN)?;
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let from_dt = self.parse_flexible_datetime(from.as_deref().unwrap_or("now"), &tz)?;
+
+        let boundaries = match unit {
+            TimeUnit::Hour => {
+                let local = from_dt.time();
+                let past_hour = chrono::Duration::minutes(i64::from(local.minute()))
+                    + chrono::Duration::seconds(i64::from(local.second()))
+                    + chrono::Duration::nanoseconds(i64::from(local.nanosecond()));
+                let latest = from_dt - past_hour;
+                (0..count)
+                    .map(|i| latest - chrono::Duration::hours(i as i64))
+                    .map(|dt| dt.to_rfc3339())
+                    .collect()
+            }
+            TimeUnit::Day => {
+                // Rebuild each local midnight from its date so DST-length days stay aligned
+                let mut date = from_dt.date_naive();
+                let mut days = Vec::with_capacity(count);
+                while days.len() < count {
+                    let start = self.start_of_local_day(&tz, date)?;
+                    if start <= from_dt {
+                        days.push(start.to_rfc3339());
+                    }
+                    date = date
+                        .pred_opt()
+                        .ok_or_else(|| TimeDateError::out_of_range("Date", date))?;
+                }
+                days
+            }
+            other => {
+                return Err(anyhow::anyhow!(
+                    "Unsupported unit: {} (expected \"hour\" or \"day\")",
+                    other
+                ))
+            }
+        };
+
+        Ok(boundaries)
+    }
+
+    /// Check whether a time (default now) falls inside business hours on a working day in a zone
+    pub async fn is_business_hours(
+        &self,
+        timezone: String,
+        day_start_hour: u32,
+        day_end_hour: u32,
+        working_days: Option<Vec<String>>,
+        at: Option<String>,
+    ) -> anyhow::Result<bool> {
+        let tz = self.parse_timezone(&timezone)?;
+        self.validate_hour_window(day_start_hour, day_end_hour)?;
+        let days = self.parse_working_days(working_days)?;
+        let local = self.parse_flexible_datetime(at.as_deref().unwrap_or("now"), &tz)?;
+
+        Ok(days.contains(&local.weekday())
+            && local.hour() >= day_start_hour
+            && local.hour() < day_end_hour)
+    }
+
+    /// Find the next time business hours open, rolling over weekends and non-working days.
+    /// Returns the first opening at or after `from`, so if business is currently open the
+    /// result is the next working day's opening.
+    pub async fn next_open(
+        &self,
+        timezone: String,
+        day_start_hour: u32,
+        day_end_hour: u32,
+        working_days: Option<Vec<String>>,
+        from: Option<String>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        let tz = self.parse_timezone(&timezone)?;
+        self.validate_hour_window(day_start_hour, day_end_hour)?;
+        let days = self.parse_working_days(working_days)?;
+        if days.is_empty() {
+            return Err(anyhow::anyhow!("working_days must not be empty"));
+        }
+        let from_dt = self.parse_flexible_datetime(from.as_deref().unwrap_or("now"), &tz)?;
+        let opening_time = NaiveTime::from_hms_opt(day_start_hour, 0, 0)
+            .ok_or_else(|| anyhow::anyhow!("Invalid day_start_hour: {}", day_start_hour))?;
+
+        for date in from_dt.date_naive().iter_days().take(8) {
+            if !days.contains(&date.weekday()) {
+                continue;
+            }
+            let open = self.resolve_local_time(&tz, date.and_time(opening_time))?;
+            if open >= from_dt {
+                return Ok(self.time_output(open, verbosity));
+            }
+        }
+
+        Err(anyhow::anyhow!("No opening found within the next week"))
+    }
+
+    /// Get this server's version and how long ago the given release date was
+    pub async fn version_age(
+        &self,
+        release_date: String,
+        at: Option<String>,
+    ) -> anyhow::Result<VersionAge> {
+        let released = self.parse_flexible_datetime(&release_date, &chrono_tz::UTC)?;
+        let reference =
+            self.parse_flexible_datetime(at.as_deref().unwrap_or("now"), &chrono_tz::UTC)?;
+        let age = reference.signed_duration_since(released);
+
+        Ok(VersionAge {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            release_date: released.to_rfc3339(),
+            age_days: age.num_days(),
+            age_seconds: age.num_seconds(),
+        })
+    }
+
+    /// Get sunrise, sunset, solar noon and day length at coordinates on a date (NOAA solar
+    /// equations). Polar days and nights are reported through `status` with no sunrise or sunset.
+    pub async fn get_sun_times(
+        &self,
+        date: String,
+        latitude: f64,
+        longitude: f64,
+        timezone: Option<String>,
+    ) -> anyhow::Result<SunTimes> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let date = self.parse_date(&date)?;
+
+        self.sun_times_for(date, latitude, longitude, &tz)
+    }
+
+    /// Get sunrise, sunset and solar noon for a named city on a date, in the city's timezone
+    pub async fn city_sun_times(&self, city: String, date: String) -> anyhow::Result<SunTimes> {
+        let found = cities::find_city(&city).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unknown city: {} (known cities: {})",
+                city,
+                cities::CITIES
+                    .iter()
+                    .map(|c| c.name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })?;
+        let tz = self.parse_timezone(found.timezone)?;
+        let date = self.parse_date(&date)?;
+
+        self.sun_times_for(date, found.latitude, found.longitude, &tz)
+    }
+
+    /// Compute local arrival time, clock change and timezones crossed for a flight
+    pub async fn zones_crossed(
+        &self,
+        departure_zone: String,
+        arrival_zone: String,
+        departure: String,
+        flight_duration_minutes: u32,
+    ) -> anyhow::Result<FlightTimes> {
+        let from_tz = self.parse_timezone(&departure_zone)?;
+        let to_tz = self.parse_timezone(&arrival_zone)?;
+        let departs = self.parse_flexible_datetime(&departure, &from_tz)?;
+        let arrives = departs
+            .checked_add_signed(chrono::Duration::minutes(i64::from(
+                flight_duration_minutes,
+            )))
+            .ok_or_else(|| TimeDateError::out_of_range("Arrival time", &departure))?
+            .with_timezone(&to_tz);
+
+        let offset_change =
+            self.offset_seconds_at(&to_tz, &arrives) - self.offset_seconds_at(&from_tz, &departs);
+        let jet_lag_hours = f64::from(offset_change) / 3600.0;
+
+        Ok(FlightTimes {
+            departure: departs.to_rfc3339(),
+            net_clock_change_minutes: arrives
+                .naive_local()
+                .signed_duration_since(departs.naive_local())
+                .num_minutes(),
+            jet_lag_hours,
+            zones_crossed: jet_lag_hours.abs().round() as u32,
+            arrival: self.format_time_info(arrives),
+        })
+    }
+
+    /// Count days between two dates using the 30/360 US (NASD) day-count convention
+    pub async fn day_count_30_360(&self, start: String, end: String) -> anyhow::Result<i64> {
+        let start_date = self
+            .parse_flexible_datetime(&start, &chrono_tz::UTC)?
+            .date_naive();
+        let end_date = self
+            .parse_flexible_datetime(&end, &chrono_tz::UTC)?
+            .date_naive();
+
+        let is_last_of_feb =
+            |date: NaiveDate| date.month() == 2 && date.day() == self.days_in_month(date.year(), 2);
+        let mut d1 = start_date.day();
+        let mut d2 = end_date.day();
+
+        if is_last_of_feb(start_date) && is_last_of_feb(end_date) {
+            d2 = 30;
+        }
+        if is_last_of_feb(start_date) {
+            d1 = 30;
+        }
+        if d2 == 31 && d1 >= 30 {
+            d2 = 30;
+        }
+        if d1 == 31 {
+            d1 = 30;
+        }
+
+        Ok(360 * i64::from(end_date.year() - start_date.year())
+            + 30 * (i64::from(end_date.month()) - i64::from(start_date.month()))
+            + (i64::from(d2) - i64::from(d1)))
+    }
+
+    /// Get the fraction (0-1) of the local calendar month that has elapsed at a time
+    pub async fn month_progress(
+        &self,
+        date_time: String,
+        timezone: Option<String>,
+    ) -> anyhow::Result<f64> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let dt = self.parse_flexible_datetime(&date_time, &tz)?;
+
+        let first = dt.date_naive().with_day(1).unwrap_or(dt.date_naive());
+        let next_first = first
+            .checked_add_months(chrono::Months::new(1))
+            .ok_or_else(|| TimeDateError::out_of_range("Date", &date_time))?;
+        let month_start = self.start_of_local_day(&tz, first)?;
+        let month_end = self.start_of_local_day(&tz, next_first)?;
+
+        let elapsed = dt.signed_duration_since(month_start).num_milliseconds() as f64;
+        let total = month_end
+            .signed_duration_since(month_start)
+            .num_milliseconds() as f64;
+        Ok(elapsed / total)
+    }
+
+    /// Resolve a relative phrase such as "in 3 days", "2 hours ago" or "next week" against a base time
+    pub async fn resolve_relative(
+        &self,
+        phrase: String,
+        base: String,
+        timezone: Option<String>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        limits::check_length("phrase", &phrase)?;
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let base_dt = self.parse_flexible_datetime(&base, &tz)?;
+        let invalid = || anyhow::anyhow!("Unrecognized relative phrase: {}", phrase);
+
+        let lowered = phrase.trim().to_lowercase();
+        let words: Vec<&str> = lowered.split_whitespace().collect();
+        let parse_amount = |word: &str| match word {
+            "a" | "an" | "one" => Some(1),
+            _ => word.parse::<i64>().ok(),
+        };
+
+        let (amount, unit) = match words.as_slice() {
+            ["in", amount, unit] => (parse_amount(amount).ok_or_else(invalid)?, *unit),
+            [amount, unit, "ago"] => (-parse_amount(amount).ok_or_else(invalid)?, *unit),
+            [amount, unit, "from", "now"] | [amount, unit, "later"] => {
+                (parse_amount(amount).ok_or_else(invalid)?, *unit)
+            }
+            ["tomorrow"] => (1, "day"),
+            ["yesterday"] => (-1, "day"),
+            ["next", unit] => (1, *unit),
+            ["last" | "previous", unit] => (-1, *unit),
+            _ => return Err(invalid()),
+        };
+
+        let resolved = self.shift_by_unit(base_dt, amount, unit.parse()?)?;
+        Ok(self.time_output(resolved, verbosity))
+    }
+
+    /// Get the ISO week containing a holiday and whether it creates a long weekend
+    pub async fn holiday_week(&self, date: String) -> anyhow::Result<HolidayWeek> {
+        let day = self.parse_date(&date)?;
+        let iso = day.iso_week();
+        let week_start = NaiveDate::from_isoywd_opt(iso.year(), iso.week(), Weekday::Mon)
+            .ok_or_else(|| TimeDateError::out_of_range("Date", &date))?;
+        let week_end = week_start
+            .checked_add_signed(chrono::Duration::days(6))
+            .ok_or_else(|| TimeDateError::out_of_range("Date", &date))?;
+
+        Ok(HolidayWeek {
+            date: day.to_string(),
+            weekday: day.format("%A").to_string(),
+            iso_year: iso.year(),
+            iso_week: iso.week(),
+            week_start: week_start.to_string(),
+            week_end: week_end.to_string(),
+            long_weekend: matches!(day.weekday(), Weekday::Mon | Weekday::Fri),
+        })
+    }
+
+    /// Find the first instant two weekly recurring meetings overlap within a horizon, if ever
+    pub async fn recurrences_collide(
+        &self,
+        a: RecurrenceSpec,
+        b: RecurrenceSpec,
+        horizon_days: u32,
+    ) -> anyhow::Result<Option<String>> {
+        limits::check_at_most("horizon_days", horizon_days, MAX_HORIZON_DAYS)?;
+        let today = self.now().date_naive();
+        let starts = [&a, &b]
+            .iter()
+            .map(|spec| match &spec.starts_on {
+                Some(date) => self.parse_date(date),
+                None => Ok(today),
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let window_start = starts.iter().min().copied().unwrap_or(today);
+        let window_end = window_start + chrono::Duration::days(i64::from(horizon_days));
+
+        let first = self.expand_weekly(&a, starts[0], window_end)?;
+        let second = self.expand_weekly(&b, starts[1], window_end)?;
+
+        let collision = first
+            .iter()
+            .flat_map(|x| second.iter().map(move |y| (x, y)))
+            .filter(|(x, y)| x.0 < y.1 && y.0 < x.1)
+            .map(|(x, y)| x.0.max(y.0))
+            .min();
+
+        Ok(collision.map(|instant| instant.to_rfc3339_opts(SecondsFormat::Secs, true)))
+    }
+
+    /// Get the fraction of a day with the sun above the horizon (1.0 for polar day, 0.0 for polar night)
+    pub async fn daylight_fraction(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        date: String,
+    ) -> anyhow::Result<f64> {
+        self.validate_coordinates(latitude, longitude)?;
+        let date = self.parse_date(&date)?;
+        let day = solar::solar_day(date, latitude, longitude);
+        Ok((day.day_length_minutes() / 1440.0).clamp(0.0, 1.0))
+    }
+
+    /// Normalize log timestamps to UTC RFC3339; inputs without an offset are read in `assume_timezone`
+    pub async fn normalize_to_utc(
+        &self,
+        inputs: Vec<String>,
+        assume_timezone: String,
+    ) -> anyhow::Result<Vec<String>> {
+        limits::check_list_len("inputs", inputs.len())?;
+        let tz = self.parse_timezone(&assume_timezone)?;
+
+        inputs
+            .iter()
+            .enumerate()
+            .map(|(index, input)| {
+                self.parse_flexible_datetime(input, &tz)
+                    .map(|dt| {
+                        dt.with_timezone(&Utc)
+                            .to_rfc3339_opts(SecondsFormat::AutoSi, true)
+                    })
+                    .map_err(|e| anyhow::anyhow!("Input {}: {}", index, e))
+            })
+            .collect()
+    }
+
+    /// Split a time window into equal slots; any leftover milliseconds go to the last slot
+    pub async fn split_window(
+        &self,
+        start: String,
+        end: String,
+        slots: usize,
+    ) -> anyhow::Result<Vec<Interval>> {
+        limits::check_between("slots", slots, 1, MAX_SERIES_LEN)?;
+        let start_dt = self.parse_flexible_datetime(&start, &chrono_tz::UTC)?;
+        let end_dt = self.parse_flexible_datetime(&end, &chrono_tz::UTC)?;
+        if end_dt <= start_dt {
+            return Err(anyhow::anyhow!("end must be after start"));
+        }
+
+        let total_ms = end_dt.signed_duration_since(start_dt).num_milliseconds();
+        let slot_ms = total_ms / slots as i64;
+        let boundary = |i: usize| {
+            if i == slots {
+                end_dt
+            } else {
+                start_dt + chrono::Duration::milliseconds(slot_ms * i as i64)
+            }
+        };
+
+        Ok((0..slots)
+            .map(|i| Interval {
+                start: boundary(i).to_rfc3339_opts(SecondsFormat::AutoSi, true),
+                end: boundary(i + 1).to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            })
+            .collect())
+    }
+
+    /// Count how many ticks of `tick_seconds` fit in the time elapsed between two instants
+    pub async fn elapsed_ticks(
+        &self,
+        start: String,
+        end: String,
+        tick_seconds: f64,
+    ) -> anyhow::Result<f64> {
+        if !tick_seconds.is_finite() || tick_seconds <= 0.0 {
+            return Err(anyhow::anyhow!(
+                "tick_seconds must be a positive number, got {}",
+                tick_seconds
+            ));
+        }
+        Ok(self.elapsed_seconds(&start, &end)? / tick_seconds)
+    }
+
+    /// Get the local first and last day of the week containing a date, with a configurable week start
+    pub async fn week_anchors(
+        &self,
+        date_time: String,
+        week_start: Option<String>,
+        timezone: Option<String>,
+    ) -> anyhow::Result<WeekAnchors> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let date = self.parse_flexible_datetime(&date_time, &tz)?.date_naive();
+        let start_day = match week_start {
+            Some(day) => self.parse_weekday(&day)?,
+            None => Weekday::Mon,
+        };
+        let first_day = self.start_of_week(date, start_day);
+        let last_day = first_day
+            .checked_add_signed(chrono::Duration::days(6))
+            .ok_or_else(|| TimeDateError::out_of_range("Date", date))?;
+
+        Ok(WeekAnchors {
+            date: date.to_string(),
+            week_start: self.weekday_name(start_day).to_string(),
+            first_day: first_day.to_string(),
+            last_day: last_day.to_string(),
+        })
+    }
+
+    /// Get the astronomical season start instants (equinoxes and solstices) for a year and hemisphere
+    pub async fn astronomical_seasons(
+        &self,
+        year: i32,
+        hemisphere: String,
+    ) -> anyhow::Result<SeasonBounds> {
+        limits::check_between("year", year, 1000, 3000)?;
+        let [march, june, september, december] = solar::equinoxes_and_solstices(year)
+            .map(|jd| self.julian_day_to_utc(jd).map(|dt| dt.to_rfc3339()));
+
+        let (spring, summer, autumn, winter) = match hemisphere.to_lowercase().as_str() {
+            "north" | "northern" => (march, june, september, december),
+            "south" | "southern" => (september, december, march, june),
+            other => {
+                return Err(anyhow::anyhow!(
+                    "Invalid hemisphere: {} (expected \"northern\" or \"southern\")",
+                    other
+                ))
+            }
+        };
+
+        Ok(SeasonBounds {
+            year,
+            hemisphere: hemisphere.to_lowercase(),
+            spring_start: spring?,
+            summer_start: summer?,
+            autumn_start: autumn?,
+            winter_start: winter?,
+        })
+    }
+
+    /// Get the time until the local day reaches a fraction (0-1) of its length, rolling to tomorrow if past
+    pub async fn time_until_day_fraction(
+        &self,
+        target_fraction: f64,
+        timezone: Option<String>,
+        from: Option<String>,
+    ) -> anyhow::Result<DurationInfo> {
+        limits::check_between("target_fraction", target_fraction, 0.0, 1.0)?;
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let from_dt = self.parse_flexible_datetime(from.as_deref().unwrap_or("now"), &tz)?;
+
+        let mut date = from_dt.date_naive();
+        let target = loop {
+            let day_start = self.start_of_local_day(&tz, date)?;
+            let next_date = date
+                .succ_opt()
+                .ok_or_else(|| TimeDateError::out_of_range("Date", date))?;
+            let day_ms = self
+                .start_of_local_day(&tz, next_date)?
+                .signed_duration_since(day_start)
+                .num_milliseconds();
+            let candidate = day_start
+                + chrono::Duration::milliseconds((day_ms as f64 * target_fraction).round() as i64);
+            if candidate >= from_dt {
+                break candidate;
+            }
+            date = next_date;
+        };
+
+        Ok(self.duration_info(target.signed_duration_since(from_dt)))
+    }
+
+    /// List monthly occurrences on a day of month, clamping to the last day in shorter months.
+    /// The first occurrence is the earliest one at or after `start`, keeping its local time of day.
+    pub async fn monthly_on_day(
+        &self,
+        day_of_month: u32,
+        start: String,
+        count: usize,
+        timezone: Option<String>,
+    ) -> anyhow::Result<Vec<String>> {
+        limits::check_between("day_of_month", day_of_month, 1, 31)?;
+        limits::check_at_most("count", count, MAX_SERIES_LEN)?;
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let start_dt = self.parse_flexible_datetime(&start, &tz)?;
+        let time_of_day = start_dt.time();
+
+        let mut year = start_dt.year();
+        let mut month = start_dt.month();
+        let mut dates = Vec::with_capacity(count);
+        while dates.len() < count {
+            let day = day_of_month.min(self.days_in_month(year, month));
+            let date = NaiveDate::from_ymd_opt(year, month, day).ok_or_else(|| {
+                TimeDateError::out_of_range("Date", format!("{year}-{month:02}-{day:02}"))
+            })?;
+            let occurrence = self.resolve_local_time(&tz, date.and_time(time_of_day))?;
+            if occurrence >= start_dt {
+                dates.push(occurrence.to_rfc3339());
+            }
+            (year, month) = if month == 12 {
+                (year + 1, 1)
+            } else {
+                (year, month + 1)
+            };
+        }
+
+        Ok(dates)
+    }
+
+    /// Get the actual number of seconds in a local calendar day (82800 or 90000 on DST transition days)
+    pub async fn seconds_in_day(&self, date: String, timezone: String) -> anyhow::Result<i64> {
+        let tz = self.parse_timezone(&timezone)?;
+        let day = self.parse_date(&date)?;
+        let next_day = day
+            .succ_opt()
+            .ok_or_else(|| TimeDateError::out_of_range("Date", &date))?;
+
+        let start = self.start_of_local_day(&tz, day)?;
+        let end = self.start_of_local_day(&tz, next_day)?;
+        Ok(end.signed_duration_since(start).num_seconds())
+    }
+
+    /// Summarize a zone's offsets over a year: standard and DST offsets, transition count and abbreviations
+    pub async fn zone_year_summary(
+        &self,
+        timezone: String,
+        year: i32,
+    ) -> anyhow::Result<ZoneYearSummary> {
+        let tz = self.parse_timezone(&timezone)?;
+        let (year_start, year_end) = self.utc_year_bounds(year)?;
+        let transitions = self.find_transitions(&tz, year_start, year_end);
+
+        let initial = tz.offset_from_utc_datetime(&year_start.naive_utc());
+        let mut offsets = vec![initial];
+        offsets.extend(
+            transitions
+                .iter()
+                .map(|instant| tz.offset_from_utc_datetime(&instant.naive_utc())),
+        );
+
+        let standard_offset = offsets
+            .iter()
+            .find(|offset| offset.dst_offset().is_zero())
+            .unwrap_or(&initial)
+            .base_utc_offset()
+            .num_seconds();
+        let dst_offset = offsets
+            .iter()
+            .find(|offset| !offset.dst_offset().is_zero())
+            .map(|offset| offset.fix().local_minus_utc());
+
+        let mut abbreviations: Vec<String> = Vec::new();
+        for offset in &offsets {
+            let name = self.offset_abbreviation(offset);
+            if !abbreviations.contains(&name) {
+                abbreviations.push(name);
+            }
+        }
+
+        Ok(ZoneYearSummary {
+            timezone: tz.name().to_string(),
+            year,
+            standard_offset: self.format_offset(standard_offset as i32),
+            dst_offset: dst_offset.map(|seconds| self.format_offset(seconds)),
+            transitions: transitions.len(),
+            abbreviations,
+        })
+    }
+
+    /// Describe the current (or given) time for you and for someone in another zone as one plain sentence
+    pub async fn schedule_summary(
+        &self,
+        my_zone: String,
+        their_zone: String,
+        at: Option<String>,
+    ) -> anyhow::Result<String> {
+        let mine = self.parse_timezone(&my_zone)?;
+        let theirs = self.parse_timezone(&their_zone)?;
+        let instant = self.parse_flexible_datetime(at.as_deref().unwrap_or("now"), &mine)?;
+        let their_time = instant.with_timezone(&theirs);
+
+        let difference =
+            self.offset_seconds_at(&theirs, &instant) - self.offset_seconds_at(&mine, &instant);
+        let relation = if difference == 0 {
+            "you share the same local time".to_string()
+        } else {
+            let minutes = i64::from(difference.unsigned_abs() / 60);
+            let mut amount = vec![self.pluralize(minutes / 60, "hour")];
+            if minutes % 60 != 0 {
+                amount.push(self.pluralize(minutes % 60, "minute"));
+            }
+            let direction = if difference > 0 { "ahead" } else { "behind" };
+            format!("they are {} {}", amount.join(" "), direction)
+        };
+
+        Ok(format!(
+            "It's {} for you and {} for them \u{2014} {}.",
+            instant.format("%-I:%M %p %A"),
+            their_time.format("%-I:%M %p %A"),
+            relation
+        ))
+    }
+
+    /// List the days within a horizon where the same local clock time in two zones is the same UTC instant
+    pub async fn aligned_local_times(
+        &self,
+        clock_time: String,
+        zone_a: String,
+        zone_b: String,
+        horizon_days: u32,
+    ) -> anyhow::Result<Vec<String>> {
+        limits::check_at_most("horizon_days", horizon_days, MAX_HORIZON_DAYS)?;
+        let (time, _) = self.parse_clock_sample(&clock_time)?;
+        let tz_a = self.parse_timezone(&zone_a)?;
+        let tz_b = self.parse_timezone(&zone_b)?;
+        let today = self.now().with_timezone(&tz_a).date_naive();
+
+        // Skipped local times (DST gaps) cannot align, so only exact local matches count
+        Ok(today
+            .iter_days()
+            .take(horizon_days as usize + 1)
+            .filter_map(|date| {
+                let local = date.and_time(time);
+                let a = tz_a.from_local_datetime(&local).earliest()?;
+                let b = tz_b.from_local_datetime(&local).earliest()?;
+                (a == b).then(|| {
+                    a.with_timezone(&Utc)
+                        .to_rfc3339_opts(SecondsFormat::Secs, true)
+                })
+            })
+            .collect())
+    }
+
+    /// Describe the time between two moments conversationally, rounded to the nearest half hour
+    pub async fn fuzzy_hours(&self, start: String, end: String) -> anyhow::Result<String> {
+        let start_dt = self.parse_flexible_datetime(&start, &chrono_tz::UTC)?;
+        let end_dt = self.parse_flexible_datetime(&end, &chrono_tz::UTC)?;
+        let seconds = end_dt.signed_duration_since(start_dt).num_seconds().abs();
+
+        let half_hours = (seconds + 900) / 1800;
+        let exact = half_hours * 1800 == seconds;
+        if half_hours == 0 {
+            return Ok(if exact {
+                "0 hours".to_string()
+            } else {
+                "less than half an hour".to_string()
+            });
+        }
+
+        let hours = half_hours / 2;
+        let phrase = match (hours, half_hours % 2 == 1) {
+            (0, _) => "half an hour".to_string(),
+            (1, false) => "1 hour".to_string(),
+            (h, false) => format!("{} hours", h),
+            (h, true) => format!("{} and a half hours", h),
+        };
+        Ok(if exact {
+            phrase
+        } else {
+            format!("about {}", phrase)
+        })
+    }
+
+    /// Get a timezone's UTC offset in whole minutes (e.g. 330 for Asia/Kolkata, -480 for Los Angeles in winter)
+    pub async fn offset_minutes(
+        &self,
+        timezone: String,
+        at: Option<String>,
+    ) -> anyhow::Result<i32> {
+        let tz = self.parse_timezone(&timezone)?;
+        let instant = self.parse_flexible_datetime(at.as_deref().unwrap_or("now"), &tz)?;
+        Ok(self.offset_seconds_at(&tz, &instant) / 60)
+    }
+
+    /// Find the next Unix timestamp after `from` that is a multiple of 10^digits (e.g. 1,800,000,000)
+    pub async fn next_round_epoch(
+        &self,
+        digits: u32,
+        from: Option<String>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        let from_dt =
+            self.parse_flexible_datetime(from.as_deref().unwrap_or("now"), &chrono_tz::UTC)?;
+        let step = 10_i64
+            .checked_pow(digits)
+            .ok_or_else(|| anyhow::anyhow!("digits is too large: {}", digits))?;
+        let next = from_dt
+            .timestamp()
+            .div_euclid(step)
+            .checked_add(1)
+            .and_then(|multiple| multiple.checked_mul(step))
+            .and_then(|seconds| DateTime::from_timestamp(seconds, 0))
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Next multiple of 10^{} is outside the supported range",
+                    digits
+                )
+            })?;
+        Ok(self.time_output(next, verbosity))
+    }
+
+    /// Express a moment as a fractional year in UTC (e.g. 2024.5 is around July 2), using the real year length
+    pub async fn decimal_year(&self, date_time: String) -> anyhow::Result<f64> {
+        let dt = self
+            .parse_flexible_datetime(&date_time, &chrono_tz::UTC)?
+            .with_timezone(&Utc);
+        let (year_start, year_end) = self.utc_year_bounds(dt.year())?;
+
+        let elapsed = dt.signed_duration_since(year_start).num_milliseconds() as f64;
+        let length = year_end
+            .signed_duration_since(year_start)
+            .num_milliseconds() as f64;
+        Ok(f64::from(dt.year()) + elapsed / length)
+    }
+
+    /// List common timezone abbreviations for a UTC offset (e.g. "-05:00" gives EST and CDT)
+    pub async fn offset_common_name(&self, offset: String) -> anyhow::Result<Vec<String>> {
+        let offset_seconds = self.parse_offset(&offset)?;
+        Ok(abbreviations::names_for_offset(offset_seconds / 60)
+            .iter()
+            .map(|name| name.to_string())
+            .collect())
+    }
+
+    /// Count weekend days and complete Saturday+Sunday weekends in a calendar month
+    pub async fn weekends_in_month(&self, year: i32, month: u32) -> anyhow::Result<WeekendCount> {
+        limits::check_year(year)?;
+        let first = NaiveDate::from_ymd_opt(year, month, 1)
+            .ok_or_else(|| anyhow::anyhow!("Invalid year/month: {}-{}", year, month))?;
+        let days: Vec<NaiveDate> = first
+            .iter_days()
+            .take_while(|date| date.month() == month)
+            .collect();
+
+        let weekend_days = days
+            .iter()
+            .filter(|date| matches!(date.weekday(), Weekday::Sat | Weekday::Sun))
+            .count() as u32;
+        // A full weekend is a Saturday whose Sunday falls in the same month
+        let full_weekends = days
+            .iter()
+            .filter(|date| date.weekday() == Weekday::Sat && date.day() < days.len() as u32)
+            .count() as u32;
+
+        Ok(WeekendCount {
+            year,
+            month,
+            weekend_days,
+            full_weekends,
+        })
+    }
+
+    /// Format the elapsed time between two moments as a stopwatch string "HH:MM:SS" (optionally ".mmm")
+    pub async fn stopwatch_format(
+        &self,
+        start: String,
+        end: String,
+        show_millis: Option<bool>,
+    ) -> anyhow::Result<String> {
+        let start_dt = self.parse_flexible_datetime(&start, &chrono_tz::UTC)?;
+        let end_dt = self.parse_flexible_datetime(&end, &chrono_tz::UTC)?;
+        let elapsed = end_dt.signed_duration_since(start_dt);
+
+        let sign = if elapsed < chrono::Duration::zero() {
+            "-"
+        } else {
+            ""
+        };
+        let total_millis = elapsed.num_milliseconds().unsigned_abs();
+        let total_seconds = total_millis / 1000;
+        // Hours keep counting past 24 rather than wrapping into days
+        let clock = format!(
+            "{}{:02}:{:02}:{:02}",
+            sign,
+            total_seconds / 3600,
+            total_seconds % 3600 / 60,
+            total_seconds % 60
+        );
+
+        Ok(if show_millis.unwrap_or(false) {
+            format!("{}.{:03}", clock, total_millis % 1000)
+        } else {
+            clock
+        })
+    }
+
+    /// Find the next date that is the nth weekday of its month (e.g. the next "first Monday"; n = -1 for last)
+    pub async fn next_nth_weekday(
+        &self,
+        weekday: String,
+        n: i32,
+        timezone: Option<String>,
+        from: Option<String>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        let day = self.parse_weekday(&weekday)?;
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let from_dt = self.parse_flexible_datetime(from.as_deref().unwrap_or("now"), &tz)?;
+        Ok(self.time_output(
+            self.next_nth_weekday_after(&tz, day, n, &from_dt)?,
+            verbosity,
+        ))
+    }
+
+    /// Get the time remaining until the next nth weekday of a month (e.g. the last Friday; n = -1)
+    pub async fn until_monthly_nth_weekday(
+        &self,
+        weekday: String,
+        n: i32,
+        timezone: Option<String>,
+        from: Option<String>,
+    ) -> anyhow::Result<DurationInfo> {
+        let day = self.parse_weekday(&weekday)?;
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let from_dt = self.parse_flexible_datetime(from.as_deref().unwrap_or("now"), &tz)?;
+        let next = self.next_nth_weekday_after(&tz, day, n, &from_dt)?;
+        Ok(self.duration_info(next.signed_duration_since(from_dt)))
+    }
+
+    /// Break a timestamp into its individual components as seen in a timezone
+    pub async fn decompose(
+        &self,
+        date_time: String,
+        timezone: Option<String>,
+    ) -> anyhow::Result<Components> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let dt = self
+            .parse_flexible_datetime(&date_time, &tz)?
+            .with_timezone(&tz);
+
+        Ok(Components {
+            year: dt.year(),
+            month: dt.month(),
+            day: dt.day(),
+            hour: dt.hour(),
+            minute: dt.minute(),
+            second: dt.second(),
+            nanosecond: dt.nanosecond(),
+            weekday: self.weekday_name(dt.weekday()).to_string(),
+            offset_seconds: self.offset_seconds_at(&tz, &dt),
+        })
+    }
+
+    /// Convert a civil time to local mean time at a longitude (4 minutes per degree from Greenwich)
+    pub async fn local_mean_time(
+        &self,
+        longitude: f64,
+        date_time: String,
+        timezone: String,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(anyhow::anyhow!(
+                "Invalid longitude {}: must be within ±180",
+                longitude
+            ));
+        }
+        let tz = self.parse_timezone(&timezone)?;
+        let civil = self.parse_flexible_datetime(&date_time, &tz)?;
+
+        // Mean solar time advances 4 minutes (240 seconds) per degree east of Greenwich
+        let mean_offset = chrono::FixedOffset::east_opt((longitude * 240.0).round() as i32)
+            .ok_or_else(|| anyhow::anyhow!("Invalid longitude {}", longitude))?;
+        Ok(self.time_output(civil.with_timezone(&mean_offset), verbosity))
+    }
+
+    /// For one UTC hour on a date, get each zone's local hour and whether it falls in 9:00-17:00
+    pub async fn local_hours_for_utc(
+        &self,
+        utc_hour: u32,
+        date: String,
+        timezones: Vec<String>,
+    ) -> anyhow::Result<Vec<ZoneHour>> {
+        limits::check_list_len("timezones", timezones.len())?;
+        let day = self.parse_date(&date)?;
+        let instant = NaiveTime::from_hms_opt(utc_hour, 0, 0)
+            .map(|time| day.and_time(time).and_utc())
+            .ok_or_else(|| {
+                anyhow::anyhow!("utc_hour must be between 0 and 23, got {}", utc_hour)
+            })?;
+
+        timezones
+            .iter()
+            .map(|name| {
+                let tz = self.parse_timezone(name)?;
+                let local = instant.with_timezone(&tz);
+                Ok(ZoneHour {
+                    timezone: tz.name().to_string(),
+                    local_time: local.to_rfc3339(),
+                    local_hour: local.hour(),
+                    is_working_hours: (9..17).contains(&local.hour()),
+                })
+            })
+            .collect()
+    }
+
+    /// Time remaining until a zone's clocks next change, or None if it has no change in the coming year
+    pub async fn dst_countdown(
+        &self,
+        timezone: String,
+        from: Option<String>,
+    ) -> anyhow::Result<Option<DurationInfo>> {
+        let tz = self.parse_timezone(&timezone)?;
+        let start = self
+            .parse_flexible_datetime(from.as_deref().unwrap_or("now"), &tz)?
+            .with_timezone(&Utc);
+        let horizon = start + chrono::Duration::days(400);
+
+        Ok(self
+            .find_transitions(&tz, start, horizon)
+            .first()
+            .map(|instant| self.duration_info(instant.signed_duration_since(start))))
+    }
+
+    /// Get which half of the year (1 for Jan-Jun, 2 for Jul-Dec) a moment falls in, with the half's first and last day
+    pub async fn half_of_year(
+        &self,
+        date_time: String,
+        timezone: Option<String>,
+    ) -> anyhow::Result<HalfOfYear> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let date = self
+            .parse_flexible_datetime(&date_time, &tz)?
+            .with_timezone(&tz)
+            .date_naive();
+
+        let half = if date.month() <= 6 { 1 } else { 2 };
+        let (first_month, last_month) = if half == 1 { (1, 6) } else { (7, 12) };
+        let first_day = NaiveDate::from_ymd_opt(date.year(), first_month, 1)
+            .ok_or_else(|| TimeDateError::out_of_range("Date", date))?;
+        let last_day = NaiveDate::from_ymd_opt(
+            date.year(),
+            last_month,
+            self.days_in_month(date.year(), last_month),
+        )
+        .ok_or_else(|| TimeDateError::out_of_range("Date", date))?;
+
+        Ok(HalfOfYear {
+            year: date.year(),
+            half,
+            first_day: first_day.to_string(),
+            last_day: last_day.to_string(),
+        })
+    }
+
+    /// Shift a time by a fractional number of seconds (e.g. 1.5), applied with nanosecond precision
+    pub async fn offset_precise(
+        &self,
+        base_time: String,
+        offset_seconds: f64,
+        timezone: Option<String>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let base = self.parse_flexible_datetime(&base_time, &tz)?;
+
+        let nanos = (offset_seconds * 1e9).round();
+        if !nanos.is_finite() || nanos.abs() >= i64::MAX as f64 {
+            return Err(anyhow::anyhow!(
+                "offset_seconds must be a finite number of at most about 292 years, got {}",
+                offset_seconds
+            ));
+        }
+        let shifted = base
+            .checked_add_signed(chrono::Duration::nanoseconds(nanos as i64))
+            .ok_or_else(|| TimeDateError::out_of_range("Resulting time", base.to_rfc3339()))?;
+        Ok(self.time_output(shifted, verbosity))
+    }
+
+    /// Get completed years of service since a start date, plus the next anniversary and days until it
+    pub async fn years_of_service(
+        &self,
+        start_date: String,
+        at: Option<String>,
+    ) -> anyhow::Result<ServiceInfo> {
+        let start = self.parse_date(&start_date)?;
+        let today = self
+            .parse_flexible_datetime(at.as_deref().unwrap_or("now"), &chrono_tz::UTC)?
+            .date_naive();
+        if today < start {
+            return Err(anyhow::anyhow!("Start date {} is after {}", start, today));
+        }
+
+        let this_year = self.anniversary_in(start, today.year())?;
+        let completed_years = self.completed_years(start, today)?;
+        let next_anniversary = if this_year >= today {
+            this_year
+        } else {
+            self.anniversary_in(start, today.year() + 1)?
+        };
+
+        Ok(ServiceInfo {
+            start_date: start.to_string(),
+            completed_years,
+            next_anniversary: next_anniversary.to_string(),
+            days_until_next: next_anniversary.signed_duration_since(today).num_days(),
+        })
+    }
+
+    /// Pick the candidate zone whose local clock at a moment is nearest a target time such as "09:00"
+    pub async fn closest_to_local_time(
+        &self,
+        target_clock: String,
+        candidate_zones: Vec<String>,
+        at: Option<String>,
+    ) -> anyhow::Result<ClosestZone> {
+        limits::check_list_len("candidate_zones", candidate_zones.len())?;
+        let (target, _) = self.parse_clock_sample(&target_clock)?;
+        let instant =
+            self.parse_flexible_datetime(at.as_deref().unwrap_or("now"), &chrono_tz::UTC)?;
+        let target_seconds = i64::from(target.num_seconds_from_midnight());
+
+        let mut best: Option<(i64, ClosestZone)> = None;
+        for name in &candidate_zones {
+            let tz = self.parse_timezone(name)?;
+            let local = instant.with_timezone(&tz);
+            // Signed distance around the 24-hour clock face, in (-12h, 12h]
+            let mut delta = i64::from(local.num_seconds_from_midnight()) - target_seconds;
+            if delta > 43_200 {
+                delta -= 86_400;
+            } else if delta <= -43_200 {
+                delta += 86_400;
+            }
+            if best
+                .as_ref()
+                .is_none_or(|(distance, _)| delta.abs() < *distance)
+            {
+                best = Some((
+                    delta.abs(),
+                    ClosestZone {
+                        timezone: tz.name().to_string(),
+                        local_time: local.to_rfc3339(),
+                        minutes_from_target: delta / 60,
+                    },
+                ));
+            }
+        }
+
+        best.map(|(_, zone)| zone)
+            .ok_or_else(|| anyhow::anyhow!("At least one candidate zone is required"))
+    }
+
+    /// Get the fraction (0-1) of an expected lifespan that has elapsed since a birth date, clamped at 1.0
+    pub async fn life_progress(
+        &self,
+        birth_date: String,
+        expected_years: u32,
+        at: Option<String>,
+    ) -> anyhow::Result<f64> {
+        if expected_years == 0 {
+            return Err(anyhow::anyhow!("expected_years must be at least 1"));
+        }
+        let birth = self.parse_date(&birth_date)?;
+        let today = self
+            .parse_flexible_datetime(at.as_deref().unwrap_or("now"), &chrono_tz::UTC)?
+            .date_naive();
+        if today < birth {
+            return Err(anyhow::anyhow!("Birth date {} is after {}", birth, today));
+        }
+
+        let years = i32::try_from(expected_years)
+            .ok()
+            .and_then(|years| birth.year().checked_add(years))
+            .ok_or_else(|| anyhow::anyhow!("expected_years is too large: {}", expected_years))?;
+        let end = self.anniversary_in(birth, years)?;
+
+        let lived = today.signed_duration_since(birth).num_days() as f64;
+        let expected = end.signed_duration_since(birth).num_days() as f64;
+        Ok((lived / expected).min(1.0))
+    }
+
+    /// Parse a duration written as ISO 8601 ("PT1H30M"), compact ("1h30m") or colon ("01:30:00") notation
+    pub async fn parse_duration(&self, input: String) -> anyhow::Result<DurationInfo> {
+        Ok(self.duration_info(self.parse_duration_text(&input)?))
+    }
+
+    /// Find the next moment matching every given constraint (weekday, day of month, month, clock time)
+    #[allow(clippy::too_many_arguments)]
+    pub async fn next_matching(
+        &self,
+        weekday: Option<String>,
+        day_of_month: Option<u32>,
+        month: Option<u32>,
+        clock_time: Option<String>,
+        timezone: Option<String>,
+        from: Option<String>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        if let Some(day) = day_of_month {
+            limits::check_between("day_of_month", day, 1, 31)?;
+        }
+        if let Some(month) = month {
+            limits::check_between("month", month, 1, 12)?;
+        }
+        let weekday = weekday.map(|name| self.parse_weekday(&name)).transpose()?;
+        let time = match clock_time {
+            Some(sample) => self.parse_clock_sample(&sample)?.0,
+            None => NaiveTime::MIN,
+        };
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let from_dt = self.parse_flexible_datetime(from.as_deref().unwrap_or("now"), &tz)?;
+
+        for date in from_dt
+            .with_timezone(&tz)
+            .date_naive()
+            .iter_days()
+            .take(MAX_HORIZON_DAYS as usize)
+        {
+            let matches = weekday.is_none_or(|day| date.weekday() == day)
+                && day_of_month.is_none_or(|day| date.day() == day)
+                && month.is_none_or(|month| date.month() == month);
+            if !matches {
+                continue;
+            }
+            let candidate = self.resolve_local_time(&tz, date.and_time(time))?;
+            if candidate > from_dt {
+                return Ok(self.time_output(candidate, verbosity));
+            }
+        }
+        Err(anyhow::anyhow!(
+            "No time matches all constraints within the next {} days",
+            MAX_HORIZON_DAYS
+        ))
+    }
+
+    /// Count how many hours of a window fall within nightly hours (e.g. 22 to 6, wrapping past midnight)
+    pub async fn night_hours_in_window(
+        &self,
+        start: String,
+        end: String,
+        night_start_hour: u32,
+        night_end_hour: u32,
+        timezone: Option<String>,
+    ) -> anyhow::Result<f64> {
+        if night_start_hour > 23 || night_end_hour > 23 || night_start_hour == night_end_hour {
+            return Err(anyhow::anyhow!(
+                "Night hours must be distinct values between 0 and 23, got {} and {}",
+                night_start_hour,
+                night_end_hour
+            ));
+        }
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let start_dt = self.parse_flexible_datetime(&start, &tz)?;
+        let end_dt = self.parse_flexible_datetime(&end, &tz)?;
+        if end_dt < start_dt {
+            return Err(anyhow::anyhow!("end must not be before start"));
+        }
+        let night_seconds =
+            self.seconds_in_daily_window(&tz, start_dt, end_dt, night_start_hour, night_end_hour)?;
+
+        Ok(night_seconds as f64 / 3600.0)
+    }
+
+    /// Compute an SLA-style deadline: advance a number of business days, then extra working hours,
+    /// rolling into the next working day whenever the daily window closes (Monday-Friday)
+    #[allow(clippy::too_many_arguments)]
+    pub async fn business_deadline(
+        &self,
+        business_days: u32,
+        extra_hours: u32,
+        timezone: String,
+        day_start_hour: u32,
+        day_end_hour: u32,
+        from: Option<String>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        limits::check_at_most("business_days", business_days, MAX_HORIZON_DAYS)?;
+        limits::check_at_most("extra_hours", extra_hours, MAX_HORIZON_DAYS * 24)?;
+        let tz = self.parse_timezone(&timezone)?;
+        self.validate_hour_window(day_start_hour, day_end_hour)?;
+        let days = self.parse_working_days(None)?;
+        let from_dt = self.parse_flexible_datetime(from.as_deref().unwrap_or("now"), &tz)?;
+
+        // The window end may be hour 24, i.e. the following midnight
+        let local_hour = |date: NaiveDate, hour: u32| {
+            self.resolve_local_time(
+                &tz,
+                date.and_time(NaiveTime::MIN) + chrono::Duration::hours(i64::from(hour)),
+            )
+        };
+        let window = |date: NaiveDate| -> anyhow::Result<(DateTime<Tz>, DateTime<Tz>)> {
+            Ok((
+                local_hour(date, day_start_hour)?,
+                local_hour(date, day_end_hour)?,
+            ))
+        };
+        let next_working_date = |date: NaiveDate| {
+            date.iter_days()
+                .skip(1)
+                .find(|next| days.contains(&next.weekday()))
+                .ok_or_else(|| TimeDateError::out_of_range("Date", date))
+        };
+
+        // Move the starting point into working time
+        let mut cursor = from_dt;
+        let mut date = cursor.date_naive();
+        loop {
+            if days.contains(&date.weekday()) {
+                let (open, close) = window(date)?;
+                if cursor < close {
+                    cursor = cursor.max(open);
+                    break;
+                }
+            }
+            date = next_working_date(date)?;
+            cursor = window(date)?.0;
+        }
+
+        // Whole business days keep the same local clock time
+        for _ in 0..business_days {
+            let time = cursor.time();
+            date = next_working_date(date)?;
+            cursor = self.resolve_local_time(&tz, date.and_time(time))?;
+        }
+
+        // Extra hours only count while the daily window is open
+        let mut remaining = chrono::Duration::hours(i64::from(extra_hours));
+        loop {
+            let close = window(date)?.1;
+            let available = close.signed_duration_since(cursor);
+            if remaining <= available {
+                cursor += remaining;
+                break;
+            }
+            remaining -= available;
+            date = next_working_date(date)?;
+            cursor = window(date)?.0;
+        }
+
+        Ok(self.time_output(cursor, verbosity))
+    }
+
+    /// Count the musical beats that elapse between two instants at a tempo in beats per minute
+    pub async fn elapsed_beats(&self, start: String, end: String, bpm: f64) -> anyhow::Result<f64> {
+        if !bpm.is_finite() || bpm <= 0.0 {
+            return Err(anyhow::anyhow!(
+                "bpm must be a positive number, got {}",
+                bpm
+            ));
+        }
+        Ok(self.elapsed_seconds(&start, &end)? / 60.0 * bpm)
+    }
+
+    /// Render a time at a legacy "GMT+5" / "UTC-3" / "GMT+5:30" style fixed offset
+    pub async fn time_gmt_offset(
+        &self,
+        date_time: String,
+        gmt_offset: String,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        let offset_seconds = self.parse_offset(&gmt_offset)?;
+        let offset = chrono::FixedOffset::east_opt(offset_seconds)
+            .ok_or_else(|| anyhow::anyhow!("Invalid UTC offset: {}", gmt_offset))?;
+        let dt = self.parse_flexible_datetime(&date_time, &chrono_tz::UTC)?;
+        Ok(self.time_output(dt.with_timezone(&offset), verbosity))
+    }
+
+    /// Get the calendar date ("YYYY-MM-DD") for an ISO week-year, week number and weekday
+    pub async fn from_iso_week(
+        &self,
+        iso_year: i32,
+        week: u32,
+        weekday: String,
+    ) -> anyhow::Result<String> {
+        let day = self.parse_weekday(&weekday)?;
+        Ok(self.iso_week_date(iso_year, week, day)?.to_string())
+    }
+
+    /// Get the fraction (0-1) of the span between two milestones that has elapsed at a moment
+    pub async fn progress_between(
+        &self,
+        start: String,
+        end: String,
+        at: Option<String>,
+    ) -> anyhow::Result<f64> {
+        let start_dt = self.parse_flexible_datetime(&start, &chrono_tz::UTC)?;
+        let end_dt = self.parse_flexible_datetime(&end, &chrono_tz::UTC)?;
+        if start_dt == end_dt {
+            return Err(anyhow::anyhow!("start and end must differ"));
+        }
+        let at_dt =
+            self.parse_flexible_datetime(at.as_deref().unwrap_or("now"), &chrono_tz::UTC)?;
+
+        let elapsed = at_dt.signed_duration_since(start_dt).num_milliseconds() as f64;
+        let total = end_dt.signed_duration_since(start_dt).num_milliseconds() as f64;
+        Ok((elapsed / total).clamp(0.0, 1.0))
+    }
+
+    /// Get the UTC instant of 12:00 local time on a date in a timezone
+    pub async fn local_noon_utc(&self, timezone: String, date: String) -> anyhow::Result<String> {
+        let tz = self.parse_timezone(&timezone)?;
+        let day = self.parse_date(&date)?;
+        let noon = day
+            .and_hms_opt(12, 0, 0)
+            .ok_or_else(|| TimeDateError::out_of_range("Date", &date))?;
+        let noon = self.resolve_local_time(&tz, noon)?;
+        Ok(noon
+            .with_timezone(&Utc)
+            .to_rfc3339_opts(SecondsFormat::Secs, true))
+    }
+
+    /// Get the signed duration from `start` to `end` ("now" is accepted for either)
+    pub async fn duration_between(
+        &self,
+        start: String,
+        end: String,
+        timezone: Option<String>,
+    ) -> anyhow::Result<DurationInfo> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let start_dt = self.parse_flexible_datetime(&start, &tz)?;
+        let end_dt = self.parse_flexible_datetime(&end, &tz)?;
+        Ok(self.duration_info(end_dt.signed_duration_since(start_dt)))
+    }
+
+    /// Describe a date relative to a reference day: "today", "yesterday", "next Monday", or the date itself
+    pub async fn relative_weekday(
+        &self,
+        date_time: String,
+        reference: Option<String>,
+        timezone: Option<String>,
+    ) -> anyhow::Result<String> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let date = self
+            .parse_flexible_datetime(&date_time, &tz)?
+            .with_timezone(&tz)
+            .date_naive();
+        let reference_date = self
+            .parse_flexible_datetime(reference.as_deref().unwrap_or("now"), &tz)?
+            .with_timezone(&tz)
+            .date_naive();
+
+        let weekday = self.weekday_name(date.weekday());
+        Ok(
+            match date.signed_duration_since(reference_date).num_days() {
+                0 => "today".to_string(),
+                1 => "tomorrow".to_string(),
+                -1 => "yesterday".to_string(),
+                2..=6 => format!("next {}", weekday),
+                -6..=-2 => format!("last {}", weekday),
+                _ => date.to_string(),
+            },
+        )
+    }
+
+    /// Read a zone's clock in one call: 12h/24h time, date, weekday, Standard/Daylight label and abbreviation
+    pub async fn clock_reading(
+        &self,
+        timezone: String,
+        at: Option<String>,
+    ) -> anyhow::Result<ClockReading> {
+        let tz = self.parse_timezone(&timezone)?;
+        let local = self
+            .parse_flexible_datetime(at.as_deref().unwrap_or("now"), &tz)?
+            .with_timezone(&tz);
+
+        Ok(ClockReading {
+            time_12h: local.format("%I:%M:%S %p").to_string(),
+            time_24h: local.format("%H:%M:%S").to_string(),
+            date: local.date_naive().to_string(),
+            weekday: self.weekday_name(local.weekday()).to_string(),
+            dst_label: if self.is_dst(&local) {
+                "Daylight"
+            } else {
+                "Standard"
+            }
+            .to_string(),
+            abbreviation: self.offset_abbreviation(local.offset()),
+        })
+    }
+
+    /// Convert a Unix timestamp (seconds or milliseconds since 1970-01-01 UTC) to a time in a timezone
+    pub async fn from_unix_timestamp(
+        &self,
+        timestamp: i64,
+        unit: Option<String>,
+        timezone: Option<String>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let unit = unit.unwrap_or_else(|| "seconds".to_string());
+        let dt = match unit.trim().to_lowercase().as_str() {
+            "seconds" | "second" | "secs" | "sec" | "s" => DateTime::from_timestamp(timestamp, 0),
+            "millis" | "milliseconds" | "millisecond" | "ms" => {
+                DateTime::from_timestamp_millis(timestamp)
+            }
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "Invalid unit: {} (expected \"seconds\" or \"millis\")",
+                    unit
+                ))
+            }
+        }
+        .ok_or_else(|| TimeDateError::out_of_range("Timestamp", format!("{timestamp} {unit}")))?;
+
+        Ok(self.time_output(dt.with_timezone(&tz), verbosity))
+    }
+
+    /// Convert a time to a Unix timestamp in both seconds and milliseconds
+    pub async fn to_unix_timestamp(
+        &self,
+        date_time: String,
+        timezone: Option<String>,
+    ) -> anyhow::Result<UnixTimestamp> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let dt = self.parse_flexible_datetime(&date_time, &tz)?;
+        Ok(UnixTimestamp {
+            seconds: dt.timestamp(),
+            milliseconds: dt.timestamp_millis(),
+        })
+    }
+
+    /// Convert a time to its Julian Day Number, Julian Date and Modified Julian Date (computed in UTC)
+    pub async fn to_julian_day(
+        &self,
+        date_time: String,
+        timezone: Option<String>,
+    ) -> anyhow::Result<JulianDate> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let dt = self.parse_flexible_datetime(&date_time, &tz)?;
+
+        // The Unix epoch is JD 2440587.5. Whole days are counted in integers so the day number
+        // is exact and only the time of day goes through floating point.
+        let seconds = dt.timestamp();
+        let julian_day_number = seconds.div_euclid(86_400) + UNIX_EPOCH_JDN;
+        let day_fraction = (seconds.rem_euclid(86_400) as f64
+            + f64::from(dt.timestamp_subsec_nanos()) / 1e9)
+            / 86_400.0;
+        let julian_date = julian_day_number as f64 - 0.5 + day_fraction;
+        Ok(JulianDate {
+            julian_day_number,
+            julian_date,
+            modified_julian_date: (julian_day_number - MJD_EPOCH_JDN) as f64 + day_fraction,
+        })
+    }
+
+    /// Convert a Julian Date (e.g. 2451545.0 for 2000-01-01 12:00 UTC) to a time, rounded to
+    /// the second
+    pub async fn from_julian_day(
+        &self,
+        julian_date: f64,
+        timezone: Option<String>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let dt = self.julian_day_to_utc(julian_date)?;
+        Ok(self.time_output(dt.with_timezone(&tz), verbosity))
+    }
+
+    /// Count how many times a standard 5-field cron expression fires within `[start, end]` in a timezone
+    pub async fn cron_count_in_window(
+        &self,
+        expression: String,
+        timezone: String,
+        start: String,
+        end: String,
+    ) -> anyhow::Result<usize> {
+        let cron = self.parse_cron(&expression)?;
+        let tz = self.parse_timezone(&timezone)?;
+        let start_dt = self.parse_flexible_datetime(&start, &tz)?;
+        let end_dt = self.parse_flexible_datetime(&end, &tz)?;
+        if end_dt < start_dt {
+            return Err(anyhow::anyhow!("end must not be before start"));
+        }
+
+        let count = cron
+            .iter_from(start_dt, croner::Direction::Forward)
+            .take_while(|firing| *firing <= end_dt)
+            .take(MAX_CRON_OCCURRENCES + 1)
+            .count();
+        if count > MAX_CRON_OCCURRENCES {
+            return Err(TimeDateError::InvalidInput {
+                argument: "window",
+                reason: format!(
+                    "must contain at most {} cron firings; narrow the window",
+                    MAX_CRON_OCCURRENCES
+                ),
+            }
+            .into());
+        }
+        Ok(count)
+    }
+
+    /// Find the timezone whose offset at a moment is closest to a requested UTC offset (e.g. "+05:20")
+    pub async fn nearest_zone_to_offset(
+        &self,
+        offset: String,
+        at: Option<String>,
+    ) -> anyhow::Result<String> {
+        let wanted = self.parse_offset(&offset)?;
+        let instant =
+            self.parse_flexible_datetime(at.as_deref().unwrap_or("now"), &chrono_tz::UTC)?;
+
+        // Prefer geographic zones over the Etc/ and legacy aliases when distances tie
+        let is_geographic = |tz: &Tz| tz.name().contains('/') && !tz.name().starts_with("Etc/");
+        TZ_VARIANTS
+            .iter()
+            .min_by_key(|tz| {
+                (
+                    (self.offset_seconds_at(tz, &instant) - wanted).abs(),
+                    !is_geographic(tz),
+                )
+            })
+            .map(|tz| tz.name().to_string())
+            .ok_or_else(|| anyhow::anyhow!("No timezones available"))
+    }
+
+    /// Get the instant halfway between two times, rounded down to the second
+    pub async fn midpoint_time(
+        &self,
+        a: String,
+        b: String,
+        timezone: Option<String>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let a_dt = self.parse_flexible_datetime(&a, &tz)?;
+        let b_dt = self.parse_flexible_datetime(&b, &tz)?;
+
+        let half = b_dt.signed_duration_since(a_dt).num_seconds().div_euclid(2);
+        Ok(self.time_output(a_dt + chrono::Duration::seconds(half), verbosity))
+    }
+
+    /// Add years, months and days using calendar arithmetic. Years and months are applied first
+    /// as one month count, clamping to the last day of shorter months (Jan 31 + 1 month is Feb 28/29);
+    /// days are then added on the local wall clock.
+    pub async fn add_calendar_period(
+        &self,
+        base_time: String,
+        years: i32,
+        months: i32,
+        days: i32,
+        timezone: Option<String>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let base = self.parse_flexible_datetime(&base_time, &tz)?;
+
+        let total_months = i64::from(years) * 12 + i64::from(months);
+        let shifted = self.shift_by_unit(base, total_months, TimeUnit::Month)?;
+        let shifted = self.shift_by_unit(shifted, i64::from(days), TimeUnit::Day)?;
+        Ok(self.time_output(shifted, verbosity))
+    }
+
+    /// List every timezone whose local clock reads the given hour (0-23) at a moment
+    pub async fn zones_at_local_hour(
+        &self,
+        hour: u32,
+        at: Option<String>,
+    ) -> anyhow::Result<Vec<String>> {
+        limits::check_between("hour", hour, 0, 23)?;
+        let instant =
+            self.parse_flexible_datetime(at.as_deref().unwrap_or("now"), &chrono_tz::UTC)?;
+        Ok(TZ_VARIANTS
+            .iter()
+            .filter(|tz| instant.with_timezone(*tz).hour() == hour)
+            .map(|tz| tz.name().to_string())
+            .collect())
+    }
+
+    /// Format a time with a custom chrono strftime pattern (e.g. "%A, %B %-d, %Y")
+    pub async fn format_datetime(
+        &self,
+        date_time: String,
+        format: String,
+        timezone: Option<String>,
+    ) -> anyhow::Result<String> {
+        limits::check_name("format pattern", &format)?;
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let dt = self
+            .parse_flexible_datetime(&date_time, &tz)?
+            .with_timezone(&tz);
+
+        // Invalid specifiers surface as a fmt::Error here rather than a panic in to_string()
+        let mut formatted = String::new();
+        write!(formatted, "{}", dt.format(&format))
+            .map_err(|_| anyhow::anyhow!("Invalid format pattern: {}", format))?;
+        Ok(formatted)
+    }
+
+    /// Pick a reproducible pseudo-random instant in `[start, end)` with millisecond resolution.
+    /// The same seed and window always yield the same result, which is handy for test fixtures.
+    pub async fn random_time_in(
+        &self,
+        start: String,
+        end: String,
+        seed: u64,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        let tz = self.timezone_or_default(None)?;
+        let start_dt = self.parse_flexible_datetime(&start, &tz)?;
+        let end_dt = self.parse_flexible_datetime(&end, &tz)?;
+
+        let span = end_dt.signed_duration_since(start_dt).num_milliseconds();
+        if span <= 0 {
+            return Err(anyhow::anyhow!(
+                "end ({}) must be at least one millisecond after start ({})",
+                end,
+                start
+            ));
+        }
+
+        let offset = self.splitmix64(seed) % span.unsigned_abs();
+        let offset = chrono::Duration::milliseconds(i64::try_from(offset)?);
+        Ok(self.time_output(start_dt + offset, verbosity))
+    }
+
+    /// Measure how much of a window is covered by a set of intervals. Intervals are clamped to
+    /// the window and overlaps are merged so no second is counted twice.
+    pub async fn coverage(
+        &self,
+        intervals: IntervalList,
+        window_start: String,
+        window_end: String,
+    ) -> anyhow::Result<CoverageInfo> {
+        limits::check_list_len("intervals", intervals.len())?;
+        let window_start_dt = self.parse_flexible_datetime(&window_start, &chrono_tz::UTC)?;
+        let window_end_dt = self.parse_flexible_datetime(&window_end, &chrono_tz::UTC)?;
+        if window_end_dt <= window_start_dt {
+            return Err(anyhow::anyhow!("window_end must be after window_start"));
+        }
+
+        let mut clamped = Vec::with_capacity(intervals.len());
+        for (index, interval) in intervals.iter().enumerate() {
+            let start = self.parse_flexible_datetime(&interval.start, &chrono_tz::UTC)?;
+            let end = self.parse_flexible_datetime(&interval.end, &chrono_tz::UTC)?;
+            if end < start {
+                return Err(anyhow::anyhow!(
+                    "Interval {}: end ({}) is before start ({})",
+                    index,
+                    interval.end,
+                    interval.start
+                ));
+            }
+            let start = start.max(window_start_dt);
+            let end = end.min(window_end_dt);
+            if end > start {
+                clamped.push((start, end));
+            }
+        }
+        clamped.sort();
+
+        let mut covered = chrono::Duration::zero();
+        let mut current: Option<(DateTime<Tz>, DateTime<Tz>)> = None;
+        for (start, end) in clamped {
+            current = match current {
+                Some((run_start, run_end)) if start <= run_end => {
+                    Some((run_start, run_end.max(end)))
+                }
+                Some((run_start, run_end)) => {
+                    covered += run_end.signed_duration_since(run_start);
+                    Some((start, end))
+                }
+                None => Some((start, end)),
+            };
+        }
+        if let Some((run_start, run_end)) = current {
+            covered += run_end.signed_duration_since(run_start);
+        }
+
+        let window = window_end_dt.signed_duration_since(window_start_dt);
+        Ok(CoverageInfo {
+            covered_seconds: covered.num_seconds(),
+            uncovered_seconds: (window - covered).num_seconds(),
+            coverage_fraction: covered.num_milliseconds() as f64 / window.num_milliseconds() as f64,
+        })
+    }
+
+    /// Get calendar metadata for the local date of a time: weekday, day of year, ISO week and quarter
+    pub async fn get_day_info(
+        &self,
+        date_time: String,
+        timezone: Option<String>,
+    ) -> anyhow::Result<DayInfo> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let day = self.parse_flexible_datetime(&date_time, &tz)?.date_naive();
+        let iso = day.iso_week();
+
+        Ok(DayInfo {
+            date: day.to_string(),
+            weekday: self.weekday_name(day.weekday()).to_string(),
+            iso_weekday: day.weekday().number_from_monday(),
+            day_of_month: day.day(),
+            day_of_year: day.ordinal(),
+            iso_year: iso.year(),
+            iso_week: iso.week(),
+            quarter: day.month0() / 3 + 1,
+            is_weekend: matches!(day.weekday(), Weekday::Sat | Weekday::Sun),
+        })
+    }
+
+    /// List every date/time input format the shared parser accepts, in the order they are tried
+    pub async fn supported_formats(&self) -> anyhow::Result<Vec<FormatSpec>> {
+        Ok(self
+            .known_formats()
+            .map(|(name, pattern, example)| FormatSpec {
+                name: name.to_string(),
+                pattern: pattern.to_string(),
+                example: example.to_string(),
+            })
+            .collect())
+    }
+
+    /// Step a number of business days forward (or backward when negative), skipping Saturdays,
+    /// Sundays and any listed holidays while keeping the local clock time. Starting on a weekend
+    /// counts from that day, so Saturday + 1 is Monday and Saturday - 1 is Friday.
+    pub async fn add_business_days(
+        &self,
+        base_time: String,
+        days: i32,
+        timezone: Option<String>,
+        holidays: Option<Vec<String>>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        let max_days = i64::from(MAX_HORIZON_DAYS);
+        limits::check_between("days", i64::from(days), -max_days, max_days)?;
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let holidays = self.parse_holidays(holidays)?;
+        let base = self.parse_flexible_datetime(&base_time, &tz)?;
+
+        let step = if days < 0 { -1 } else { 1 };
+        let mut date = base.date_naive();
+        for _ in 0..days.unsigned_abs() {
+            loop {
+                date = date
+                    .checked_add_signed(chrono::Duration::days(step))
+                    .ok_or_else(|| TimeDateError::out_of_range("Date", date))?;
+                if self.is_business_day(date, &holidays) {
+                    break;
+                }
+            }
+        }
+
+        let result = self.resolve_local_time(&tz, date.and_time(base.time()))?;
+        Ok(self.time_output(result, verbosity))
+    }
+
+    /// Count business days (Monday-Friday, excluding listed holidays) from the earlier date up to
+    /// the later one, which is only counted when `inclusive_end` is true. The count is negative
+    /// when `end` is before `start`.
+    pub async fn count_business_days(
+        &self,
+        start: String,
+        end: String,
+        timezone: Option<String>,
+        holidays: Option<Vec<String>>,
+        inclusive_end: Option<bool>,
+    ) -> anyhow::Result<i64> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let holidays = self.parse_holidays(holidays)?;
+        let start_date = self.parse_flexible_datetime(&start, &tz)?.date_naive();
+        let end_date = self.parse_flexible_datetime(&end, &tz)?.date_naive();
+
+        let (first, last, sign) = if end_date < start_date {
+            (end_date, start_date, -1)
+        } else {
+            (start_date, end_date, 1)
+        };
+        limits::check_span_days("range", last.signed_duration_since(first).num_days())?;
+
+        let include_last = inclusive_end.unwrap_or(false);
+        let count = first
+            .iter_days()
+            .take_while(|date| *date < last || (include_last && *date == last))
+            .filter(|date| self.is_business_day(*date, &holidays))
+            .count();
+        Ok(sign * count as i64)
+    }
+
+    /// Express the difference between two times in the largest readable unit, from seconds up to
+    /// years (months are 1/12 of an average Gregorian year)
+    pub async fn smart_difference(&self, start: String, end: String) -> anyhow::Result<SmartDiff> {
+        let seconds = self.elapsed_seconds(&start, &end)?;
+        let units = [
+            ("year", 31_556_952.0),
+            ("month", 2_629_746.0),
+            ("week", 604_800.0),
+            ("day", 86_400.0),
+            ("hour", 3_600.0),
+            ("minute", 60.0),
+        ];
+        let (unit, unit_seconds) = units
+            .iter()
+            .find(|(_, unit_seconds)| seconds.abs() >= *unit_seconds)
+            .copied()
+            .unwrap_or(("second", 1.0));
+
+        let value = (seconds / unit_seconds * 100.0).round() / 100.0;
+        let label = if value.abs() == 1.0 {
+            unit.to_string()
+        } else {
+            format!("{unit}s")
+        };
+        Ok(SmartDiff {
+            value,
+            unit: format!("{unit}s"),
+            humanized: format!("{value} {label}"),
+        })
+    }
+
+    /// List a zone's daylight-saving transitions in a year, in order. Southern-hemisphere zones
+    /// spring forward late in the year; zones without DST return an empty list.
+    pub async fn get_dst_transitions(
+        &self,
+        timezone: String,
+        year: i32,
+    ) -> anyhow::Result<Vec<DstTransition>> {
+        let tz = self.parse_timezone(&timezone)?;
+        let (year_start, year_end) = self.utc_year_bounds(year)?;
+
+        Ok(self
+            .find_transitions(&tz, year_start, year_end)
+            .into_iter()
+            .filter_map(|instant| self.dst_transition_at(&tz, instant))
+            .collect())
+    }
+
+    /// Get a zone's current abbreviation, offsets and DST state together with its next DST
+    /// transition (looking up to 400 days ahead). `dst_offset` is None for zones without DST.
+    pub async fn get_timezone_details(&self, timezone: String) -> anyhow::Result<TimezoneDetails> {
+        let tz = self.parse_timezone(&timezone)?;
+        let now = self.now();
+        let current = tz.offset_from_utc_datetime(&now.naive_utc());
+        let is_dst = !current.dst_offset().is_zero();
+        let next_transition = self
+            .find_transitions(&tz, now, now + chrono::Duration::days(400))
+            .into_iter()
+            .find_map(|instant| self.dst_transition_at(&tz, instant));
+
+        // Outside DST the next DST transition moves clocks into it
+        let dst_offset = if is_dst {
+            Some(self.format_offset(current.fix().local_minus_utc()))
+        } else {
+            next_transition
+                .as_ref()
+                .map(|transition| transition.offset_after.clone())
+        };
+
+        Ok(TimezoneDetails {
+            name: tz.name().to_string(),
+            abbreviation: self.offset_abbreviation(&current),
+            utc_offset: self.format_offset(current.fix().local_minus_utc()),
+            standard_offset: self.format_offset(current.base_utc_offset().num_seconds() as i32),
+            dst_offset,
+            is_dst,
+            next_transition,
+        })
+    }
+
+    /// Estimate the jet lag of travelling between two zones on a date: the offset difference,
+    /// direction and roughly one adaptation day per timezone crossed
+    pub async fn jet_lag(
+        &self,
+        from_zone: String,
+        to_zone: String,
+        travel_date: String,
+    ) -> anyhow::Result<JetLag> {
+        let from_tz = self.parse_timezone(&from_zone)?;
+        let to_tz = self.parse_timezone(&to_zone)?;
+        let travel = self.parse_flexible_datetime(&travel_date, &from_tz)?;
+
+        let difference =
+            self.offset_seconds_at(&to_tz, &travel) - self.offset_seconds_at(&from_tz, &travel);
+        let hours = f64::from(difference) / 3600.0;
+        let direction = match difference.signum() {
+            1 => "eastward",
+            -1 => "westward",
+            _ => "none",
+        };
+
+        Ok(JetLag {
+            offset_difference_hours: hours,
+            direction: direction.to_string(),
+            adaptation_days: hours.abs().min(24.0 - hours.abs()).abs().round() as u32,
+        })
+    }
+
+    /// List every local top-of-hour instant in `[start, end]`. Hours skipped by a spring-forward
+    /// are absent and hours repeated by a fall-back appear twice with their different offsets.
+    pub async fn hourly_grid(
+        &self,
+        start: String,
+        end: String,
+        timezone: String,
+    ) -> anyhow::Result<Vec<String>> {
+        let tz = self.parse_timezone(&timezone)?;
+        let start_dt = self
+            .parse_flexible_datetime(&start, &tz)?
+            .with_timezone(&Utc);
+        let end_dt = self.parse_flexible_datetime(&end, &tz)?.with_timezone(&Utc);
+        if end_dt < start_dt {
+            return Err(anyhow::anyhow!("end must not be before start"));
+        }
+        limits::check_span_hours("window", end_dt.signed_duration_since(start_dt))?;
+
+        // Walk local wall-clock hours rather than a UTC grid, since historic offsets such as
+        // Monrovia's -00:44:30 put local tops of the hour at odd UTC seconds. Offsets stay under a
+        // day, so a day of margin around the window covers every local reading inside it.
+        let margin = chrono::Duration::days(1);
+        let first = start_dt
+            .naive_utc()
+            .checked_sub_signed(margin)
+            .unwrap_or(NaiveDateTime::MIN);
+        let last = end_dt
+            .naive_utc()
+            .checked_add_signed(margin)
+            .unwrap_or(NaiveDateTime::MAX);
+        let first_hour = NaiveTime::from_hms_opt(first.hour(), 0, 0)
+            .ok_or_else(|| TimeDateError::out_of_range("Time", first))?;
+
+        let mut instants = Vec::new();
+        let mut hour = Some(first.date().and_time(first_hour));
+        while let Some(local) = hour.filter(|local| *local <= last) {
+            let readings = match tz.from_local_datetime(&local) {
+                LocalResult::Single(instant) => vec![instant],
+                LocalResult::Ambiguous(earliest, latest) => vec![earliest, latest],
+                LocalResult::None => vec![],
+            };
+            instants.extend(
+                readings
+                    .into_iter()
+                    .filter(|instant| (start_dt..=end_dt).contains(&instant.with_timezone(&Utc))),
+            );
+            hour = local.checked_add_signed(chrono::Duration::hours(1));
+        }
+        // A fall-back of more than an hour interleaves the readings of the repeated hours
+        instants.sort();
+        let grid = instants
+            .iter()
+            .map(|instant| instant.to_rfc3339())
+            .collect();
+        Ok(grid)
+    }
+
+    /// Explain how an input is parsed: which format matched, whether it carried its own offset,
+    /// the resulting UTC instant and its local rendering. Lists the attempted formats on failure.
+    pub async fn parse_datetime(
+        &self,
+        input: String,
+        timezone: Option<String>,
+    ) -> anyhow::Result<ParseReport> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let mut report = ParseReport {
+            input: input.clone(),
+            matched: false,
+            format: None,
+            pattern: None,
+            has_offset: false,
+            utc: None,
+            local: None,
+            error: None,
+            attempted_formats: Vec::new(),
+        };
+
+        match self.detect_datetime_format(&input, &tz, Disambiguation::Earliest) {
+            Ok(Some((dt, name))) => {
+                report.matched = true;
+                report.format = Some(name.to_string());
+                report.pattern = self
+                    .known_formats()
+                    .find(|(known, _, _)| *known == name)
+                    .map(|(_, pattern, _)| pattern.to_string());
+                report.has_offset = BUILTIN_DATE_TIME_FORMATS
+                    .iter()
+                    .chain(OFFSET_DATE_TIME_FORMATS)
+                    .any(|(known, _, _)| *known == name);
+                report.utc = Some(
+                    dt.with_timezone(&Utc)
+                        .to_rfc3339_opts(SecondsFormat::AutoSi, true),
+                );
+                report.local = Some(self.format_time_info(dt));
+            }
+            Ok(None) => {
+                report.attempted_formats = self
+                    .known_formats()
+                    .map(|(name, _, _)| name.to_string())
+                    .collect();
+            }
+            Err(e) => report.error = Some(e.to_string()),
+        }
+        Ok(report)
+    }
+
+    /// Time left until a shift ends at a local clock time today, or zero once it has ended.
+    /// With `overnight`, an end time already passed today is taken to be tomorrow's.
+    pub async fn shift_remaining(
+        &self,
+        shift_end_clock: String,
+        timezone: String,
+        from: Option<String>,
+        overnight: Option<bool>,
+    ) -> anyhow::Result<DurationInfo> {
+        let tz = self.parse_timezone(&timezone)?;
+        let (end_clock, _) = self.parse_clock_sample(&shift_end_clock)?;
+        let now = self.parse_flexible_datetime(from.as_deref().unwrap_or("now"), &tz)?;
+
+        let mut end = self.resolve_local_time(&tz, now.date_naive().and_time(end_clock))?;
+        if end <= now && overnight.unwrap_or(false) {
+            let tomorrow = now
+                .date_naive()
+                .succ_opt()
+                .ok_or_else(|| TimeDateError::out_of_range("Date", now.date_naive()))?;
+            end = self.resolve_local_time(&tz, tomorrow.and_time(end_clock))?;
+        }
+
+        let remaining = end.signed_duration_since(now).max(chrono::Duration::zero());
+        Ok(self.duration_info(remaining))
+    }
+
+    /// Check whether an instant is at, or within an hour of, one of a zone's clock changes
+    pub async fn is_transition_instant(
+        &self,
+        timezone: String,
+        date_time: String,
+    ) -> anyhow::Result<TransitionCheck> {
+        let tz = self.parse_timezone(&timezone)?;
+        let instant = self
+            .parse_flexible_datetime(&date_time, &tz)?
+            .with_timezone(&Utc);
+
+        let nearest = self
+            .find_transitions(
+                &tz,
+                instant - chrono::Duration::days(1),
+                instant + chrono::Duration::days(1),
+            )
+            .into_iter()
+            .min_by_key(|transition| {
+                instant
+                    .signed_duration_since(*transition)
+                    .num_seconds()
+                    .abs()
+            });
+        let Some(transition) = nearest else {
+            return Ok(TransitionCheck {
+                is_transition: false,
+                near_transition: false,
+                nearest_transition: None,
+                seconds_from_transition: None,
+                offset_before: None,
+                offset_after: None,
+            });
+        };
+
+        let delta = instant.signed_duration_since(transition).num_seconds();
+        let offset_at = |at: DateTime<Utc>| self.format_offset(self.offset_seconds_at(&tz, &at));
+        Ok(TransitionCheck {
+            is_transition: delta == 0,
+            near_transition: delta.abs() <= 3600,
+            nearest_transition: Some(transition.to_rfc3339_opts(SecondsFormat::Secs, true)),
+            seconds_from_transition: Some(delta),
+            offset_before: Some(offset_at(transition - chrono::Duration::seconds(1))),
+            offset_after: Some(offset_at(transition)),
+        })
+    }
+
+    /// Order two instants after normalizing both to UTC, so equal instants in different zones
+    /// compare as "equal"
+    pub async fn compare_datetimes(
+        &self,
+        a: String,
+        b: String,
+        a_timezone: Option<String>,
+        b_timezone: Option<String>,
+    ) -> anyhow::Result<DateTimeComparison> {
+        let a_tz = self.timezone_or_default(a_timezone.as_deref())?;
+        let b_tz = self.timezone_or_default(b_timezone.as_deref())?;
+        let a_dt = self.parse_flexible_datetime(&a, &a_tz)?.with_timezone(&Utc);
+        let b_dt = self.parse_flexible_datetime(&b, &b_tz)?.with_timezone(&Utc);
+
+        let ordering = match a_dt.cmp(&b_dt) {
+            std::cmp::Ordering::Less => "before",
+            std::cmp::Ordering::Equal => "equal",
+            std::cmp::Ordering::Greater => "after",
+        };
+        Ok(DateTimeComparison {
+            ordering: ordering.to_string(),
+            difference_seconds: b_dt.signed_duration_since(a_dt).num_milliseconds() as f64 / 1000.0,
+        })
+    }
+
+    /// Get a Monday-first month grid with the country's public holidays (from the embedded
+    /// holiday table) and the month's weekend days marked
+    pub async fn month_calendar_with_holidays(
+        &self,
+        year: i32,
+        month: u32,
+        country_code: String,
+    ) -> anyhow::Result<AnnotatedMonthGrid> {
+        limits::check_year(year)?;
+        let first = NaiveDate::from_ymd_opt(year, month, 1)
+            .ok_or_else(|| anyhow::anyhow!("Invalid year/month: {}-{}", year, month))?;
+        let holidays = holidays::holidays_in(&country_code, year).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unknown country code: {} (supported: {})",
+                country_code,
+                holidays::COUNTRY_CODES.join(", ")
+            )
+        })?;
+
+        let days: Vec<NaiveDate> = first
+            .iter_days()
+            .take(self.days_in_month(year, month) as usize)
+            .collect();
+        let mut cells: Vec<Option<u32>> =
+            vec![None; first.weekday().num_days_from_monday() as usize];
+        cells.extend(days.iter().map(|day| Some(day.day())));
+        cells.resize(cells.len().div_ceil(7) * 7, None);
+
+        Ok(AnnotatedMonthGrid {
+            year,
+            month,
+            country_code: country_code.trim().to_ascii_uppercase(),
+            weeks: cells.chunks(7).map(<[Option<u32>]>::to_vec).collect(),
+            holidays: holidays
+                .into_iter()
+                .filter(|(date, _)| date.month() == month)
+                .map(|(date, name)| HolidayEntry {
+                    date: date.to_string(),
+                    name: name.to_string(),
+                })
+                .collect(),
+            weekends: days
+                .iter()
+                .filter(|day| matches!(day.weekday(), Weekday::Sat | Weekday::Sun))
+                .map(NaiveDate::to_string)
+                .collect(),
+        })
+    }
+
+    /// Get the next firing times of a 5-field cron expression after `from`, evaluated on the
+    /// zone's local clock so schedules keep their wall-clock time across DST changes
+    pub async fn next_cron_times(
+        &self,
+        expression: String,
+        from: String,
+        count: usize,
+        timezone: Option<String>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<Vec<TimeOutput>> {
+        limits::check_between("count", count, 1, MAX_SERIES_LEN)?;
+        let cron = self.parse_cron(&expression)?;
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let from_dt = self.parse_flexible_datetime(&from, &tz)?;
+
+        Ok(cron
+            .iter_after(from_dt)
+            .take(count)
+            .map(|firing| self.time_output(firing, verbosity))
+            .collect())
+    }
+
+    /// Get an age in fractional years multiplied by a scale factor, e.g. 7 for "dog years"
+    pub async fn scaled_age(
+        &self,
+        birth_date: String,
+        scale: f64,
+        at: Option<String>,
+    ) -> anyhow::Result<f64> {
+        if !scale.is_finite() || scale < 0.0 {
+            return Err(anyhow::anyhow!(
+                "scale must be a non-negative number, got {}",
+                scale
+            ));
+        }
+        let birth = self.parse_date(&birth_date)?;
+        let today = self
+            .parse_flexible_datetime(at.as_deref().unwrap_or("now"), &chrono_tz::UTC)?
+            .date_naive();
+        if today < birth {
+            return Err(anyhow::anyhow!("Birth date {} is after {}", birth, today));
+        }
+
+        Ok(self.fractional_age(birth, today)? * scale)
+    }
+
+    /// Describe a time relative to a reference (default now) as "3 hours ago" or "in 2 days",
+    /// rounding to the nearest unit; differences under a minute read "just now"
+    pub async fn humanize_relative(
+        &self,
+        date_time: String,
+        reference: Option<String>,
+        timezone: Option<String>,
+    ) -> anyhow::Result<RelativeTime> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let target = self.parse_flexible_datetime(&date_time, &tz)?;
+        let base = self.parse_flexible_datetime(reference.as_deref().unwrap_or("now"), &tz)?;
+
+        let seconds = target.signed_duration_since(base).num_seconds();
+        let magnitude = seconds.unsigned_abs() as f64;
+        let rounded = |unit_seconds: f64| (magnitude / unit_seconds).round() as i64;
+
+        let phrase = if magnitude < 60.0 {
+            None
+        } else if rounded(60.0) < 60 {
+            Some(self.pluralize(rounded(60.0), "minute"))
+        } else if rounded(3_600.0) < 24 {
+            Some(self.pluralize(rounded(3_600.0), "hour"))
+        } else if rounded(86_400.0) < 7 {
+            Some(self.pluralize(rounded(86_400.0), "day"))
+        } else if rounded(86_400.0) < 30 {
+            Some(self.pluralize(rounded(604_800.0), "week"))
+        } else if rounded(2_629_746.0) < 12 {
+            Some(self.pluralize(rounded(2_629_746.0), "month"))
+        } else {
+            Some(self.pluralize(rounded(31_556_952.0), "year"))
+        };
+
+        let text = match phrase {
+            None => "just now".to_string(),
+            Some(phrase) if seconds < 0 => format!("{phrase} ago"),
+            Some(phrase) => format!("in {phrase}"),
+        };
+        Ok(RelativeTime { text, seconds })
+    }
+
+    /// Get the instant a fraction of the way through a local calendar day, scaling by the day's
+    /// actual length (23 or 25 hours on DST transition days)
+    pub async fn from_day_fraction(
+        &self,
+        date: String,
+        fraction: f64,
+        timezone: Option<String>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        if !(0.0..1.0).contains(&fraction) {
+            return Err(anyhow::anyhow!(
+                "fraction must be in [0, 1), got {}",
+                fraction
+            ));
+        }
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let day = self.parse_date(&date)?;
+        let next_day = day
+            .succ_opt()
+            .ok_or_else(|| TimeDateError::out_of_range("Date", &date))?;
+
+        let start = self.start_of_local_day(&tz, day)?;
+        let end = self.start_of_local_day(&tz, next_day)?;
+        let length_ms = end.signed_duration_since(start).num_milliseconds() as f64;
+        let offset = chrono::Duration::milliseconds((length_ms * fraction).round() as i64);
+        Ok(self.time_output(start + offset, verbosity))
+    }
+
+    /// Scan a year for the earliest and latest local sunrise and sunset clock times at a location.
+    /// Polar days and nights have neither and are counted separately.
+    pub async fn solar_extremes(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        year: i32,
+        timezone: String,
+    ) -> anyhow::Result<SolarExtremes> {
+        self.validate_coordinates(latitude, longitude)?;
+        limits::check_year(year)?;
+        let tz = self.parse_timezone(&timezone)?;
+        let first = NaiveDate::from_ymd_opt(year, 1, 1)
+            .ok_or_else(|| TimeDateError::out_of_range("Year", year))?;
+
+        let mut sunrises = Vec::new();
+        let mut sunsets = Vec::new();
+        let (mut polar_days, mut polar_nights) = (0, 0);
+        for date in first.iter_days().take_while(|date| date.year() == year) {
+            let day = solar::solar_day(date, latitude, longitude);
+            match day.status {
+                solar::DaylightStatus::PolarDay => polar_days += 1,
+                solar::DaylightStatus::PolarNight => polar_nights += 1,
+                solar::DaylightStatus::Normal => {}
+            }
+            let utc_midnight = date.and_time(NaiveTime::MIN).and_utc();
+            let at_minutes = |minutes: f64| {
+                utc_midnight
+                    .checked_add_signed(chrono::Duration::seconds((minutes * 60.0).round() as i64))
+                    .map(|instant| instant.with_timezone(&tz))
+                    .ok_or_else(|| TimeDateError::out_of_range("Year", year))
+            };
+            sunrises.extend(day.sunrise_minutes.map(at_minutes).transpose()?);
+            sunsets.extend(day.sunset_minutes.map(at_minutes).transpose()?);
+        }
+
+        let extremes = |events: &[DateTime<Tz>]| {
+            let earliest = events.iter().min_by_key(|event| event.time());
+            let latest = events.iter().max_by_key(|event| event.time());
+            (
+                earliest.map(|event| event.to_rfc3339()),
+                latest.map(|event| event.to_rfc3339()),
+            )
+        };
+        let (earliest_sunrise, latest_sunrise) = extremes(&sunrises);
+        let (earliest_sunset, latest_sunset) = extremes(&sunsets);
+
+        Ok(SolarExtremes {
+            year,
+            timezone: tz.name().to_string(),
+            earliest_sunrise,
+            latest_sunrise,
+            earliest_sunset,
+            latest_sunset,
+            polar_days,
+            polar_nights,
+        })
+    }
+
+    /// Get the current time in several zones from one snapshot of "now", so every entry shows
+    /// the same instant. Invalid zones are reported per entry.
+    pub async fn get_current_times(
+        &self,
+        timezones: Vec<String>,
+    ) -> anyhow::Result<Vec<ZoneClock>> {
+        limits::check_list_len("timezones", timezones.len())?;
+        let now = self.now();
+
+        Ok(timezones
+            .into_iter()
+            .map(|timezone| match self.parse_timezone(&timezone) {
+                Ok(tz) => ZoneClock {
+                    info: Some(self.timezone_info_at(tz.name(), &now.with_timezone(&tz))),
+                    error: None,
+                    timezone,
+                },
+                Err(e) => ZoneClock {
+                    timezone,
+                    info: None,
+                    error: Some(e.to_string()),
+                },
+            })
+            .collect())
+    }
+
+    /// Get a log correlation key: the start of the UTC bucket containing an instant, as epoch
+    /// seconds, so the same instant written in different zones always yields the same key
+    pub async fn correlation_key(
+        &self,
+        date_time: String,
+        bucket_seconds: u64,
+    ) -> anyhow::Result<String> {
+        let bucket = i64::try_from(bucket_seconds)
+            .ok()
+            .filter(|bucket| *bucket > 0)
+            .ok_or_else(|| {
+                anyhow::anyhow!("bucket_seconds must be positive, got {}", bucket_seconds)
+            })?;
+        let epoch = self
+            .parse_flexible_datetime(&date_time, &chrono_tz::UTC)?
+            .timestamp();
+
+        Ok((epoch.div_euclid(bucket) * bucket).to_string())
+    }
+
+    /// Report how precise a written timestamp is, from which components are present: a bare date,
+    /// minutes, seconds, or fractional seconds (by the number of digits)
+    pub async fn timestamp_precision(&self, input: String) -> anyhow::Result<PrecisionInfo> {
+        let trimmed = input.trim();
+        if trimmed.eq_ignore_ascii_case("now") {
+            return Err(anyhow::anyhow!(
+                "\"now\" is not a written timestamp and has no precision"
+            ));
+        }
+        let (_, format) = self
+            .detect_datetime_format(trimmed, &chrono_tz::UTC, Disambiguation::Earliest)?
+            .ok_or_else(|| TimeDateError::invalid_date_format(trimmed))?;
+
+        // The clock is the first token with a colon; anything from a sign or "Z" on is the offset
+        let clock = trimmed
+            .split(|c: char| c.is_whitespace() || c == 'T')
+            .find(|token| token.contains(':'))
+            .map(|token| {
+                let end = token
+                    .char_indices()
+                    .skip(1)
+                    .find(|(_, c)| matches!(c, '+' | '-' | 'Z' | 'z'))
+                    .map_or(token.len(), |(index, _)| index);
+                &token[..end]
+            });
+        let fields: Vec<&str> = clock
+            .map(|clock| clock.split(':').collect())
+            .unwrap_or_default();
+        let fractional_digits = fields
+            .get(2)
+            .and_then(|seconds| seconds.split_once('.'))
+            .map_or(0, |(_, fraction)| fraction.len());
+
+        let precision = match (fields.len(), fractional_digits) {
+            (0, _) => "date",
+            (2, _) => "minute",
+            (_, 0) => "second",
+            (_, 1..=3) => "millisecond",
+            (_, 4..=6) => "microsecond",
+            _ => "nanosecond",
+        };
+        Ok(PrecisionInfo {
+            precision: precision.to_string(),
+            format: format.to_string(),
+            fractional_digits,
+        })
+    }
+
+    /// Get the local start (inclusive) and end (exclusive) of the week, month, quarter or year
+    /// containing a time. Weeks start on `week_start` (default Monday).
+    pub async fn get_period_bounds(
+        &self,
+        date_time: String,
+        period: String,
+        timezone: Option<String>,
+        week_start: Option<String>,
+    ) -> anyhow::Result<PeriodBounds> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let date = self.parse_flexible_datetime(&date_time, &tz)?.date_naive();
+        let start_day = match week_start {
+            Some(day) => self.parse_weekday(&day)?,
+            None => Weekday::Mon,
+        };
+
+        let period = period.trim().to_lowercase();
+        let out_of_range = || TimeDateError::out_of_range("Date", date);
+        let months_from = |month0: u32, months: u32| {
+            let first =
+                NaiveDate::from_ymd_opt(date.year(), month0 + 1, 1).ok_or_else(out_of_range)?;
+            let next = first
+                .checked_add_months(chrono::Months::new(months))
+                .ok_or_else(out_of_range)?;
+            Ok::<_, anyhow::Error>((first, next))
+        };
+        let (first, next) = match period.as_str() {
+            "week" => {
+                let first = self.start_of_week(date, start_day);
+                let next = first
+                    .checked_add_signed(chrono::Duration::days(7))
+                    .ok_or_else(out_of_range)?;
+                (first, next)
+            }
+            "month" => months_from(date.month0(), 1)?,
+            "quarter" => months_from(date.month0() / 3 * 3, 3)?,
+            "year" => months_from(0, 12)?,
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "Invalid period: {} (expected week, month, quarter or year)",
+                    period
+                ))
+            }
+        };
+
+        Ok(PeriodBounds {
+            start: self.start_of_local_day(&tz, first)?.to_rfc3339(),
+            end: self.start_of_local_day(&tz, next)?.to_rfc3339(),
+            period,
+        })
+    }
+
+    /// Get the fiscal year and quarter containing the local date of a time, for a fiscal year
+    /// starting on the first of `fiscal_year_start_month` (1 = January, i.e. calendar quarters)
+    pub async fn get_fiscal_period(
+        &self,
+        date_time: String,
+        fiscal_year_start_month: u32,
+        timezone: Option<String>,
+    ) -> anyhow::Result<FiscalPeriod> {
+        limits::check_between("fiscal_year_start_month", fiscal_year_start_month, 1, 12)?;
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let date = self
+            .parse_flexible_datetime(&date_time, &tz)?
+            .with_timezone(&tz)
+            .date_naive();
+
+        let out_of_range = || TimeDateError::out_of_range("Date", date);
+        let months_in = (date.month() + 12 - fiscal_year_start_month) % 12;
+        let start_year = if date.month() >= fiscal_year_start_month {
+            date.year()
+        } else {
+            date.year() - 1
+        };
+        let year_start = NaiveDate::from_ymd_opt(start_year, fiscal_year_start_month, 1)
+            .ok_or_else(out_of_range)?;
+        let after = |first: NaiveDate, months: u32| {
+            first
+                .checked_add_months(chrono::Months::new(months))
+                .and_then(|next| next.pred_opt())
+                .ok_or_else(out_of_range)
+        };
+        let year_end = after(year_start, 12)?;
+        let quarter_start = year_start
+            .checked_add_months(chrono::Months::new(months_in / 3 * 3))
+            .ok_or_else(out_of_range)?;
+        let quarter_end = after(quarter_start, 3)?;
+
+        Ok(FiscalPeriod {
+            fiscal_year: format!("FY{}", year_end.year()),
+            fiscal_quarter: months_in / 3 + 1,
+            quarter_start: quarter_start.to_string(),
+            quarter_end: quarter_end.to_string(),
+            year_start: year_start.to_string(),
+            year_end: year_end.to_string(),
+        })
+    }
+
+    /// Group the zones using an abbreviation at a moment (default: now) by continent prefix, so
+    /// "GMT" in January gives Africa, America, Atlantic and Europe. Legacy and Etc/ names without
+    /// a continent are left out.
+    pub async fn abbreviation_regions(
+        &self,
+        abbreviation: String,
+        at: Option<String>,
+    ) -> anyhow::Result<Vec<RegionGroup>> {
+        let wanted = abbreviation.trim();
+        let instant =
+            self.parse_flexible_datetime(at.as_deref().unwrap_or("now"), &chrono_tz::UTC)?;
+
+        let mut matches: Vec<(&str, i32, &str)> = TZ_VARIANTS
+            .iter()
+            .filter_map(|tz| {
+                let (region, _) = tz.name().split_once('/')?;
+                if region == "Etc" {
+                    return None;
+                }
+                let offset = tz.offset_from_utc_datetime(&instant.naive_utc());
+                offset
+                    .abbreviation()
+                    .filter(|name| name.eq_ignore_ascii_case(wanted))
+                    .map(|_| (region, offset.fix().local_minus_utc(), tz.name()))
+            })
+            .collect();
+        if matches.is_empty() {
+            return Err(anyhow::anyhow!(
+                "No timezone uses the abbreviation {} at {}",
+                wanted,
+                instant.to_rfc3339()
+            ));
+        }
+        matches.sort();
+
+        // The same letters can mean different offsets (IST in Asia and Europe), so split on both
+        let mut groups: Vec<RegionGroup> = Vec::new();
+        for (region, offset_seconds, zone) in matches {
+            let offset = self.format_offset(offset_seconds);
+            match groups.last_mut() {
+                Some(group) if group.region == region && group.offset == offset => {
+                    group.zone_count += 1;
+                    if group.example_zones.len() < 5 {
+                        group.example_zones.push(zone.to_string());
+                    }
+                }
+                _ => groups.push(RegionGroup {
+                    region: region.to_string(),
+                    offset,
+                    zone_count: 1,
+                    example_zones: vec![zone.to_string()],
+                }),
+            }
+        }
+        Ok(groups)
+    }
+
+    /// Get an age as completed years, months and days on the calendar, plus the total day count.
+    /// Month steps clamp to the end of shorter months, so a Feb 29 birthday turns a year older on
+    /// Feb 28 in common years.
+    pub async fn calculate_age(
+        &self,
+        birth_date: String,
+        as_of: Option<String>,
+        timezone: Option<String>,
+    ) -> anyhow::Result<AgeInfo> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let birth = self.parse_date(&birth_date)?;
+        let today = self
+            .parse_flexible_datetime(as_of.as_deref().unwrap_or("now"), &tz)?
+            .with_timezone(&tz)
+            .date_naive();
+        if today < birth {
+            return Err(anyhow::anyhow!("Birth date {} is after {}", birth, today));
+        }
+
+        let out_of_range = || TimeDateError::out_of_range("Date", birth);
+        let mut months =
+            (today.year() - birth.year()) * 12 + today.month() as i32 - birth.month() as i32;
+        let mut anchor = birth
+            .checked_add_months(chrono::Months::new(months as u32))
+            .ok_or_else(out_of_range)?;
+        if anchor > today {
+            months -= 1;
+            anchor = birth
+                .checked_add_months(chrono::Months::new(months as u32))
+                .ok_or_else(out_of_range)?;
+        }
+
+        Ok(AgeInfo {
+            birth_date: birth.to_string(),
+            as_of: today.to_string(),
+            years: months / 12,
+            months: (months % 12) as u32,
+            days: today.signed_duration_since(anchor).num_days(),
+            total_days: today.signed_duration_since(birth).num_days(),
+        })
+    }
+
+    /// Find the next window in which a 5-field cron expression is active: the next firing after
+    /// `from` (default: now), the first minute after it that does not fire, and the firing after
+    /// that. An every-15-minutes expression is active for one minute at a time, while
+    /// "* 9-17 * * 1-5" stays active through the working day.
+    pub async fn cron_window(
+        &self,
+        expression: String,
+        timezone: String,
+        from: Option<String>,
+    ) -> anyhow::Result<CronWindow> {
+        let cron = self.parse_cron(&expression)?;
+        let tz = self.parse_timezone(&timezone)?;
+        let from_dt = self.parse_flexible_datetime(from.as_deref().unwrap_or("now"), &tz)?;
+
+        let never_fires = || anyhow::anyhow!("Cron expression '{}' never fires", expression);
+        let mut firings = cron.iter_after(from_dt);
+        let active_from = firings.next().ok_or_else(never_fires)?;
+        let mut last = active_from;
+        for _ in 0..MAX_CRON_OCCURRENCES {
+            let firing = firings.next().ok_or_else(never_fires)?;
+            if firing.signed_duration_since(last) > chrono::Duration::minutes(1) {
+                return Ok(CronWindow {
+                    active_from: self.format_time_info(active_from),
+                    active_until: self.format_time_info(last + chrono::Duration::minutes(1)),
+                    next_active_from: self.format_time_info(firing),
+                });
+            }
+            last = firing;
+        }
+        Err(anyhow::anyhow!(
+            "Cron fires on more than {} consecutive minutes; it may never stop",
+            MAX_CRON_OCCURRENCES
+        ))
+    }
+
+    /// List timezones grouped under their current UTC offset, west to east, optionally filtered
+    /// by a name substring like `list_timezones`
+    pub async fn timezones_by_offset(
+        &self,
+        filter: Option<String>,
+    ) -> anyhow::Result<Vec<OffsetGroup>> {
+        let now = self.now();
+        let mut zones: Vec<(i32, String)> = self
+            .list_timezones_internal(filter, None)
+            .await?
+            .into_iter()
+            .filter_map(|name| {
+                let tz = Tz::from_str(&name).ok()?;
+                Some((self.offset_seconds_at(&tz, &now), name))
+            })
+            .collect();
+        zones.sort();
+
+        let mut groups: Vec<OffsetGroup> = Vec::new();
+        for (offset_seconds, name) in zones {
+            let offset = self.format_offset(offset_seconds);
+            match groups.last_mut() {
+                Some(group) if group.offset == offset => group.timezones.push(name),
+                _ => groups.push(OffsetGroup {
+                    offset,
+                    timezones: vec![name],
+                }),
+            }
+        }
+        Ok(groups)
+    }
+
+    /// Snap a time to the start of a working day. A time on a working day at or after
+    /// `day_start_hour` is already inside that day and comes back unchanged; anything earlier,
+    /// or on a non-working day, moves forward to the next working day's start.
+    pub async fn snap_to_workday_start(
+        &self,
+        date_time: String,
+        timezone: String,
+        day_start_hour: u32,
+        working_days: Option<Vec<String>>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        let tz = self.parse_timezone(&timezone)?;
+        let days = self.parse_working_days(working_days)?;
+        if days.is_empty() {
+            return Err(anyhow::anyhow!("working_days must not be empty"));
+        }
+        let local = self
+            .parse_flexible_datetime(&date_time, &tz)?
+            .with_timezone(&tz);
+        let start_time = NaiveTime::from_hms_opt(day_start_hour, 0, 0)
+            .ok_or_else(|| anyhow::anyhow!("Invalid day_start_hour: {}", day_start_hour))?;
+
+        for date in local.date_naive().iter_days().take(8) {
+            if !days.contains(&date.weekday()) {
+                continue;
+            }
+            let start = self.resolve_local_time(&tz, date.and_time(start_time))?;
+            if date == local.date_naive() && local >= start {
+                return Ok(self.time_output(local, verbosity));
+            }
+            if start >= local {
+                return Ok(self.time_output(start, verbosity));
+            }
+        }
+
+        Err(anyhow::anyhow!("No working day found within the next week"))
+    }
+
+    /// Get the time elapsed between two instants minus a daily pause window on the zone's local
+    /// clock (e.g. maintenance from 2 to 4, or 23 to 1 wrapping past midnight). Pauses cut by the
+    /// start or end of the span only subtract their overlapping part.
+    pub async fn elapsed_excluding_window(
+        &self,
+        start: String,
+        end: String,
+        pause_start_hour: u32,
+        pause_end_hour: u32,
+        timezone: String,
+    ) -> anyhow::Result<DurationInfo> {
+        if pause_start_hour > 23 || pause_end_hour > 23 || pause_start_hour == pause_end_hour {
+            return Err(anyhow::anyhow!(
+                "Pause hours must be distinct values between 0 and 23, got {} and {}",
+                pause_start_hour,
+                pause_end_hour
+            ));
+        }
+        let tz = self.parse_timezone(&timezone)?;
+        let start_dt = self.parse_flexible_datetime(&start, &tz)?;
+        let end_dt = self.parse_flexible_datetime(&end, &tz)?;
+        if end_dt < start_dt {
+            return Err(anyhow::anyhow!("end must not be before start"));
+        }
+
+        let paused =
+            self.seconds_in_daily_window(&tz, start_dt, end_dt, pause_start_hour, pause_end_hour)?;
+        let elapsed = end_dt.signed_duration_since(start_dt).num_seconds() - paused;
+        Ok(self.duration_info(chrono::Duration::seconds(elapsed)))
+    }
+
+    /// Parse an ISO 8601 week date such as "2024-W03-1" or "2024W031" into the start of that day.
+    /// The weekday digit (1 = Monday) is optional and defaults to Monday. Early-January and
+    /// late-December dates can belong to the neighbouring ISO year: "2020-W53-5" is 2021-01-01.
+    pub async fn parse_iso_week(
+        &self,
+        week_date: String,
+        timezone: Option<String>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let invalid = || {
+            anyhow::anyhow!(
+                "Invalid ISO week date: {} (expected e.g. \"2024-W03-1\")",
+                week_date
+            )
+        };
+        let upper = week_date.trim().to_ascii_uppercase();
+        let (year, rest) = upper.split_once('W').ok_or_else(invalid)?;
+        let year = year.strip_suffix('-').unwrap_or(year);
+        let (week, day) = match rest.split_once('-') {
+            Some((week, day)) => (week, day),
+            None if rest.len() > 2 => rest.split_at(2),
+            None => (rest, "1"),
+        };
+        let digits =
+            |part: &str, len: usize| part.len() == len && part.bytes().all(|b| b.is_ascii_digit());
+        if !(digits(year, 4) && digits(week, 2) && digits(day, 1)) {
+            return Err(invalid());
+        }
+        let weekday = match day.parse::<u8>().map_err(|_| invalid())? {
+            day @ 1..=7 => Weekday::try_from(day - 1).map_err(|_| invalid())?,
+            _ => return Err(invalid()),
+        };
+
+        let date = self.iso_week_date(
+            year.parse().map_err(|_| invalid())?,
+            week.parse().map_err(|_| invalid())?,
+            weekday,
+        )?;
+        Ok(self.time_output(self.start_of_local_day(&tz, date)?, verbosity))
+    }
+
+    /// Format the local date of a time as an ISO 8601 week date ("YYYY-Www-D", Monday = 1).
+    /// The year is the ISO week-year, so 2021-01-01 is "2020-W53-5".
+    pub async fn to_iso_week(
+        &self,
+        date_time: String,
+        timezone: Option<String>,
+    ) -> anyhow::Result<String> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let day = self
+            .parse_flexible_datetime(&date_time, &tz)?
+            .with_timezone(&tz)
+            .date_naive();
+        Ok(day.format("%G-W%V-%u").to_string())
+    }
+
+    /// Check whether the local date of a time is the last day of its month, quarter or year
+    pub async fn is_period_end(
+        &self,
+        date_time: String,
+        period: String,
+        timezone: Option<String>,
+    ) -> anyhow::Result<bool> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let date = self
+            .parse_flexible_datetime(&date_time, &tz)?
+            .with_timezone(&tz)
+            .date_naive();
+
+        // The last day of a month is the one whose successor starts another month
+        let month_end = date
+            .succ_opt()
+            .is_none_or(|next| next.month() != date.month());
+        match period.trim().to_lowercase().as_str() {
+            "month" => Ok(month_end),
+            "quarter" => Ok(month_end && date.month() % 3 == 0),
+            "year" => Ok(month_end && date.month() == 12),
+            other => Err(anyhow::anyhow!(
+                "Invalid period: {} (expected month, quarter or year)",
+                other
+            )),
+        }
+    }
+
+    /// Compute a follow-the-sun SLA deadline: working time elapses while any zone's shift is
+    /// open, so work rolls from one zone's closing into the next zone's opening and overlapping
+    /// shifts count once. Shifts run Monday to Friday on each zone's local clock. The deadline is
+    /// reported in the zone whose shift it falls in.
+    pub async fn sla_deadline_multi(
+        &self,
+        start: String,
+        hours: f64,
+        zones: SupportZoneList,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        limits::check_between("hours", hours, 0.0, f64::from(MAX_HORIZON_DAYS * 24))?;
+        if zones.is_empty() {
+            return Err(anyhow::anyhow!("zones must not be empty"));
+        }
+        limits::check_list_len("zones", zones.len())?;
+        let shifts = zones
+            .iter()
+            .map(|zone| {
+                self.validate_hour_window(zone.day_start_hour, zone.day_end_hour)?;
+                Ok((
+                    self.parse_timezone(&zone.timezone)?,
+                    zone.day_start_hour,
+                    zone.day_end_hour,
+                ))
+            })
+            .collect::<anyhow::Result<Vec<(Tz, u32, u32)>>>()?;
+        let days = self.parse_working_days(None)?;
+        let start_dt = self
+            .parse_flexible_datetime(&start, &chrono_tz::UTC)?
+            .with_timezone(&Utc);
+        let horizon = start_dt + chrono::Duration::days(i64::from(MAX_HORIZON_DAYS));
+
+        // First shift of a zone on a working day at or after `date`; the end may be hour 24
+        let next_shift = |(tz, start_hour, end_hour): &(Tz, u32, u32), date: NaiveDate| {
+            let date = date
+                .iter_days()
+                .find(|day| days.contains(&day.weekday()))
+                .ok_or_else(|| TimeDateError::out_of_range("Date", date))?;
+            let local_hour = |hour: u32| {
+                self.resolve_local_time(
+                    tz,
+                    date.and_time(NaiveTime::MIN) + chrono::Duration::hours(i64::from(hour)),
+                )
+                .map(|dt| dt.with_timezone(&Utc))
+            };
+            anyhow::Ok((local_hour(*start_hour)?, local_hour(*end_hour)?, date))
+        };
+
+        // Walk every zone's shifts merged by opening time, starting a day early so a shift that
+        // is already open at `start` counts
+        let first_day = start_dt
+            .date_naive()
+            .pred_opt()
+            .ok_or_else(|| TimeDateError::out_of_range("Date", &start))?;
+        let mut upcoming = shifts
+            .iter()
+            .map(|shift| next_shift(shift, first_day))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let mut cursor = start_dt;
+        let mut remaining = chrono::Duration::milliseconds((hours * 3_600_000.0).round() as i64);
+        loop {
+            let (index, (open, close, date)) = upcoming
+                .iter()
+                .copied()
+                .enumerate()
+                .min_by_key(|(_, (open, _, _))| *open)
+                .ok_or_else(|| anyhow::anyhow!("zones must not be empty"))?;
+            if open > horizon {
+                return Err(anyhow::anyhow!(
+                    "Deadline is more than {} days away",
+                    MAX_HORIZON_DAYS
+                ));
+            }
+            let from = open.max(cursor);
+            if close > from {
+                let available = close.signed_duration_since(from);
+                if remaining <= available {
+                    let deadline = (from + remaining).with_timezone(&shifts[index].0);
+                    return Ok(self.time_output(deadline, verbosity));
+                }
+                remaining -= available;
+                cursor = close;
+            }
+            let next_day = date
+                .succ_opt()
+                .ok_or_else(|| TimeDateError::out_of_range("Date", date))?;
+            upcoming[index] = next_shift(&shifts[index], next_day)?;
+        }
+    }
+
+    /// Build a conversion grid: one row per input time (read in `base_zone` when it has no
+    /// offset) and one column per target zone, each cell the local time as
+    /// "YYYY-MM-DD HH:MM" plus the zone abbreviation
+    pub async fn conversion_matrix(
+        &self,
+        base_zone: String,
+        times: Vec<String>,
+        target_zones: Vec<String>,
+    ) -> anyhow::Result<Vec<Vec<String>>> {
+        limits::check_list_len("times", times.len())?;
+        limits::check_list_len("target_zones", target_zones.len())?;
+        let base = self.parse_timezone(&base_zone)?;
+        let targets = target_zones
+            .iter()
+            .map(|zone| self.parse_timezone(zone))
+            .collect::<anyhow::Result<Vec<Tz>>>()?;
+
+        times
+            .iter()
+            .map(|time| {
+                let instant = self.parse_flexible_datetime(time, &base)?;
+                Ok(targets
+                    .iter()
+                    .map(|tz| {
+                        instant
+                            .with_timezone(tz)
+                            .format("%Y-%m-%d %H:%M %Z")
+                            .to_string()
+                    })
+                    .collect())
+            })
+            .collect()
+    }
+
+    /// Convert one time into several target zones from the same source instant. Invalid target
+    /// zones are reported per entry instead of failing the whole conversion.
+    pub async fn convert_to_many(
+        &self,
+        time: String,
+        from_timezone: String,
+        to_timezones: Vec<String>,
+    ) -> anyhow::Result<Vec<ZoneConversion>> {
+        limits::check_list_len("to_timezones", to_timezones.len())?;
+        let from_tz = self.parse_timezone_as(&from_timezone, "source timezone")?;
+        let dt = self.parse_flexible_datetime(&time, &from_tz)?;
+
+        Ok(to_timezones
+            .into_iter()
+            .map(
+                |timezone| match self.parse_timezone_as(&timezone, "target timezone") {
+                    Ok(tz) => ZoneConversion {
+                        time: Some(self.format_time_info(dt.with_timezone(&tz))),
+                        error: None,
+                        timezone,
+                    },
+                    Err(e) => ZoneConversion {
+                        timezone,
+                        time: None,
+                        error: Some(e.to_string()),
+                    },
+                },
+            )
+            .collect())
+    }
+
+    /// Find the UTC ranges on a UTC date during which every participant is inside their local
+    /// hour window, keeping only ranges of at least `duration_minutes`. Windows may wrap past
+    /// midnight (e.g. 22 to 2); participants who never overlap give an empty list.
+    pub async fn find_meeting_slots(
+        &self,
+        participants: ParticipantList,
+        date: String,
+        duration_minutes: u32,
+    ) -> anyhow::Result<Vec<Interval>> {
+        if participants.is_empty() {
+            return Err(anyhow::anyhow!("participants must not be empty"));
+        }
+        limits::check_list_len("participants", participants.len())?;
+        limits::check_between("duration_minutes", duration_minutes, 1, 24 * 60)?;
+        let day = self.parse_date(&date)?;
+        let day_start = day.and_time(NaiveTime::MIN).and_utc();
+        let day_end = day_start + chrono::Duration::days(1);
+
+        let mut free = vec![(day_start, day_end)];
+        for participant in &participants {
+            let (start_hour, end_hour) = (participant.start_hour, participant.end_hour);
+            if start_hour > 23 || end_hour > 24 || start_hour == end_hour {
+                return Err(anyhow::anyhow!(
+                    "Invalid hour window {}-{} for {}: hours must differ, start 0-23 and end 0-24",
+                    start_hour,
+                    end_hour,
+                    participant.timezone
+                ));
+            }
+            let tz = self.parse_timezone(&participant.timezone)?;
+            let local_hour = |date: NaiveDate, hour: u32| {
+                self.resolve_local_time(
+                    &tz,
+                    date.and_time(NaiveTime::MIN) + chrono::Duration::hours(i64::from(hour)),
+                )
+                .map(|dt| dt.with_timezone(&Utc))
+            };
+
+            // Local dates around the UTC date cover every window that can reach into it
+            let mut windows = Vec::new();
+            for date in [day.pred_opt(), Some(day), day.succ_opt()]
+                .into_iter()
+                .flatten()
+            {
+                let end_date = if end_hour < start_hour {
+                    date.succ_opt()
+                        .ok_or_else(|| TimeDateError::out_of_range("Date", date))?
+                } else {
+                    date
+                };
+                windows.push((
+                    local_hour(date, start_hour)?,
+                    local_hour(end_date, end_hour)?,
+                ));
+            }
+
+            free = free
+                .iter()
+                .flat_map(|&(free_start, free_end)| {
+                    windows.iter().filter_map(move |&(start, end)| {
+                        let (start, end) = (start.max(free_start), end.min(free_end));
+                        (start < end).then_some((start, end))
+                    })
+                })
+                .collect();
+            free.sort();
+            // Back-to-back windows (e.g. 0 to 24 on consecutive days) form one range
+            free.dedup_by(|next, previous| {
+                if next.0 <= previous.1 {
+                    previous.1 = previous.1.max(next.1);
+                    true
+                } else {
+                    false
+                }
+            });
+        }
+
+        let minimum = chrono::Duration::minutes(i64::from(duration_minutes));
+        Ok(free
+            .into_iter()
+            .filter(|(start, end)| end.signed_duration_since(*start) >= minimum)
+            .map(|(start, end)| Interval {
+                start: start.to_rfc3339_opts(SecondsFormat::Secs, true),
+                end: end.to_rfc3339_opts(SecondsFormat::Secs, true),
+            })
+            .collect())
+    }
+
+    /// Get how far one zone's clock is ahead of another's at a moment (default: now), in minutes
+    /// and as a sentence. Each offset is taken at that instant, so DST in only one of the zones
+    /// changes the answer.
+    pub async fn timezone_offset_difference(
+        &self,
+        from_timezone: String,
+        to_timezone: String,
+        at: Option<String>,
+    ) -> anyhow::Result<OffsetDifference> {
+        let from_tz = self.parse_timezone_as(&from_timezone, "source timezone")?;
+        let to_tz = self.parse_timezone_as(&to_timezone, "target timezone")?;
+        let instant =
+            self.parse_flexible_datetime(at.as_deref().unwrap_or("now"), &chrono_tz::UTC)?;
+
+        let minutes = (self.offset_seconds_at(&to_tz, &instant)
+            - self.offset_seconds_at(&from_tz, &instant))
+            / 60;
+        // "America/Argentina/Buenos_Aires" reads as "Buenos Aires"
+        let place = |tz: &Tz| {
+            tz.name()
+                .rsplit('/')
+                .next()
+                .unwrap_or(tz.name())
+                .replace('_', " ")
+        };
+        let magnitude = minutes.unsigned_abs();
+        let amount = match (magnitude / 60, magnitude % 60) {
+            (hours, 0) => self.pluralize(i64::from(hours), "hour"),
+            (0, mins) => self.pluralize(i64::from(mins), "minute"),
+            (hours, mins) => format!(
+                "{} {}",
+                self.pluralize(i64::from(hours), "hour"),
+                self.pluralize(i64::from(mins), "minute")
+            ),
+        };
+        let description = match minutes.signum() {
+            0 => format!("{} has the same time as {}", place(&to_tz), place(&from_tz)),
+            1 => format!(
+                "{} is {} ahead of {}",
+                place(&to_tz),
+                amount,
+                place(&from_tz)
+            ),
+            _ => format!("{} is {} behind {}", place(&to_tz), amount, place(&from_tz)),
+        };
+
+        Ok(OffsetDifference {
+            minutes,
+            description,
+        })
+    }
+
+    /// Count down to a target time from `from` (default: now). A target that has already passed
+    /// is not an error: `has_passed` is set and the remaining duration is zero.
+    pub async fn countdown_to(
+        &self,
+        target: String,
+        from: Option<String>,
+        timezone: Option<String>,
+    ) -> anyhow::Result<Countdown> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let target_dt = self.parse_flexible_datetime(&target, &tz)?;
+        let from_dt = self.parse_flexible_datetime(from.as_deref().unwrap_or("now"), &tz)?;
+
+        let remaining = target_dt.signed_duration_since(from_dt);
+        let has_passed = remaining <= chrono::Duration::zero();
+        Ok(Countdown {
+            target: target_dt.to_rfc3339(),
+            remaining: self.duration_info(remaining.max(chrono::Duration::zero())),
+            has_passed,
+        })
+    }
+
+    /// Run several tools in one call, returning one entry per operation in the same order. Each
+    /// operation names a tool and its arguments; a failure is reported in its own entry without
+    /// stopping the rest unless `stop_on_error` is set, which leaves later operations out of the
+    /// response. Batches cannot be nested.
+    pub async fn batch(
+        &self,
+        operations: BatchOperationList,
+        stop_on_error: Option<bool>,
+    ) -> anyhow::Result<Vec<BatchResult>> {
+        limits::check_list_len("operations", operations.len())?;
+
+        let mut results = Vec::with_capacity(operations.len());
+        for BatchOperation {
+            operation,
+            arguments,
+        } in operations
+        {
+            let outcome = if operation == "batch" {
+                Err("Batches cannot be nested".to_string())
+            } else {
+                self.dispatch_tool(CallToolRequestParam {
+                    name: operation.clone(),
+                    arguments,
+                })
+                .await
+                .map_err(|e| e.message)
+            };
+            let (result, error) = match outcome {
+                Ok(output) => (Some(output), None),
+                Err(message) => (None, Some(message)),
+            };
+            let failed = error.is_some();
+            results.push(BatchResult {
+                operation,
+                result,
+                error,
+            });
+            if failed && stop_on_error.unwrap_or(false) {
+                break;
+            }
+        }
+        Ok(results)
+    }
+
+    /// Round a time down ("floor"), up ("ceil") or to the nearest ("nearest") minute, 5 or 15
+    /// minutes, hour or day on the zone's local clock, so flooring to a day gives local midnight.
+    /// Nearest rounds halfway points up and compares real elapsed time, so 23-hour DST days work.
+    pub async fn round_datetime(
+        &self,
+        date_time: String,
+        granularity: String,
+        mode: String,
+        timezone: Option<String>,
+        verbosity: Option<Verbosity>,
+    ) -> anyhow::Result<TimeOutput> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let dt = self
+            .parse_flexible_datetime(&date_time, &tz)?
+            .with_timezone(&tz);
+        let local = dt.naive_local();
+        let out_of_range = || TimeDateError::out_of_range("Time", local);
+
+        let step_minutes = match granularity.trim().to_lowercase().as_str() {
+            "minute" | "1min" => Some(1),
+            "5min" => Some(5),
+            "15min" => Some(15),
+            "hour" => Some(60),
+            "day" => None,
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "Invalid granularity: {} (expected minute, 5min, 15min, hour or day)",
+                    granularity
+                ))
+            }
+        };
+        let (floor, next) = match step_minutes {
+            Some(step) => {
+                let minute = local.minute() - local.minute() % step;
+                let floor = local.date().and_time(
+                    NaiveTime::from_hms_opt(local.hour(), minute, 0).ok_or_else(out_of_range)?,
+                );
+                (
+                    floor,
+                    floor.checked_add_signed(chrono::Duration::minutes(i64::from(step))),
+                )
+            }
+            None => {
+                let floor = local.date().and_time(NaiveTime::MIN);
+                (floor, floor.checked_add_signed(chrono::Duration::days(1)))
+            }
+        };
+        // The next boundary is only needed, and may only overflow, when rounding up
+        let ceil = if floor == local { Some(floor) } else { next };
+        let up = || self.boundary_instant(&tz, ceil.ok_or_else(out_of_range)?, &dt, false);
+
+        let down = self.boundary_instant(&tz, floor, &dt, true)?;
+        let rounded = match mode.trim().to_lowercase().as_str() {
+            "floor" => down,
+            "ceil" => up()?,
+            "nearest" => {
+                let up = up()?;
+                if dt.signed_duration_since(down) < up.signed_duration_since(dt) {
+                    down
+                } else {
+                    up
+                }
+            }
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "Invalid mode: {} (expected floor, ceil or nearest)",
+                    mode
+                ))
+            }
+        };
+        Ok(self.time_output(rounded, verbosity))
+    }
+
+    /// Check whether a time falls inside a weekly schedule: a daily `window_start` to
+    /// `window_end` local window (e.g. "09:00" to "17:30") on the allowed weekdays. A window
+    /// ending at or before its start crosses midnight (equal times span a whole day) and belongs
+    /// to the weekday it starts on, so 22:00-06:00 on Friday covers early Saturday. An empty
+    /// weekday list never matches.
+    pub async fn is_within_schedule(
+        &self,
+        date_time: String,
+        weekdays: Vec<String>,
+        window_start: String,
+        window_end: String,
+        timezone: Option<String>,
+    ) -> anyhow::Result<ScheduleCheck> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let days = self.parse_working_days(Some(weekdays))?;
+        let (start_time, _) = self.parse_clock_sample(&window_start)?;
+        let (end_time, _) = self.parse_clock_sample(&window_end)?;
+        let dt = self
+            .parse_flexible_datetime(&date_time, &tz)?
+            .with_timezone(&tz);
+
+        let out_of_range = || TimeDateError::out_of_range("Date", dt.date_naive());
+        let first_day = dt.date_naive().pred_opt().ok_or_else(out_of_range)?;
+        // Yesterday's window may still be open; a week and a day ahead reaches every weekday
+        for date in first_day.iter_days().take(9) {
+            if !days.contains(&date.weekday()) {
+                continue;
+            }
+            let end_date = if end_time <= start_time {
+                date.succ_opt().ok_or_else(out_of_range)?
+            } else {
+                date
+            };
+            let start = self.resolve_local_time(&tz, date.and_time(start_time))?;
+            let end = self.resolve_local_time(&tz, end_date.and_time(end_time))?;
+            if start <= dt && dt < end {
+                return Ok(ScheduleCheck {
+                    within_schedule: true,
+                    next_start: None,
+                });
+            }
+            if start > dt {
+                return Ok(ScheduleCheck {
+                    within_schedule: false,
+                    next_start: Some(start.to_rfc3339()),
+                });
+            }
+        }
+
+        Ok(ScheduleCheck {
+            within_schedule: false,
+            next_start: None,
+        })
+    }
+
+    /// Get the leap-year status, length and month lengths of a year in the proleptic Gregorian
+    /// calendar
+    pub async fn calendar_facts(&self, year: i32) -> anyhow::Result<CalendarFacts> {
+        limits::check_year(year)?;
+        let is_leap_year = self.leap_year(year);
+        let february = if is_leap_year { 29 } else { 28 };
+        Ok(CalendarFacts {
+            year,
+            is_leap_year,
+            days_in_year: if is_leap_year { 366 } else { 365 },
+            days_in_month: vec![31, february, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31],
+        })
+    }
+
+    /// Check whether a year is a leap year: divisible by 4, except centuries not divisible by 400
+    pub async fn is_leap_year(&self, year: i32) -> anyhow::Result<bool> {
+        limits::check_year(year)?;
+        Ok(self.leap_year(year))
+    }
+
+    /// Resolve a timezone name, abbreviation ("PST"), Windows zone id ("Pacific Standard Time")
+    /// or whole-hour offset ("GMT+2") to its IANA zone. Every tool accepts these forms; ambiguous
+    /// abbreviations such as "CST" list their candidates instead of picking one.
+    pub async fn resolve_timezone(&self, timezone: String) -> anyhow::Result<ResolvedTimezone> {
+        limits::check_name("timezone", &timezone)?;
+        let (source, zones) = self
+            .match_timezone(&timezone)
+            .ok_or_else(|| self.unknown_timezone(&timezone, "timezone"))?;
+        let candidates: Vec<String> = zones.iter().map(|tz| tz.name().to_string()).collect();
+
+        Ok(ResolvedTimezone {
+            timezone: match &candidates[..] {
+                [single] => Some(single.clone()),
+                _ => None,
+            },
+            input: timezone,
+            candidates,
+            source: source.to_string(),
+        })
+    }
+
+    /// Express the local date of a time in another calendar: the proleptic Julian calendar
+    /// (13 days behind Gregorian from 1900 to 2099), an ISO week date, or Unix seconds for the
+    /// instant itself
+    pub async fn to_alternate_calendar(
+        &self,
+        date_time: String,
+        calendar: Calendar,
+        timezone: Option<String>,
+    ) -> anyhow::Result<AlternateCalendarDate> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+        let dt = self
+            .parse_flexible_datetime(&date_time, &tz)?
+            .with_timezone(&tz);
+        let date = dt.date_naive();
+
+        let value = match calendar {
+            Calendar::Julian => {
+                let (year, month, day) = self.julian_calendar_date(date);
+                format!("{year:04}-{month:02}-{day:02}")
+            }
+            Calendar::Iso => date.format("%G-W%V-%u").to_string(),
+            Calendar::Unix => dt.timestamp().to_string(),
+        };
+        Ok(AlternateCalendarDate {
+            calendar,
+            value,
+            gregorian_date: date.to_string(),
+        })
+    }
+
+    // Resources - Read-only data accessible via MCP resource URIs
+
+    /// Get current time as a resource
+    #[mcp_resource(
+        uri_template = "timedate://current-time/{timezone}",
+        name = "current_time",
+        description = "Current time in the specified timezone",
+        mime_type = "application/json"
+    )]
+    pub async fn current_time_resource(&self, timezone: String) -> anyhow::Result<TimeInfo> {
+        let tz_option = if timezone == "local" {
+            None
+        } else {
+            Some(timezone)
+        };
+        self.get_current_time_internal(tz_option).await
+    }
+
+    /// Get timezone information as a resource
+    #[mcp_resource(
+        uri_template = "timedate://timezone-info",
+        name = "timezone_info",
+        description = "Information about the local timezone",
+        mime_type = "application/json"
+    )]
+    pub async fn timezone_info_resource(&self) -> anyhow::Result<TimezoneInfo> {
+        self.get_timezone_info_internal().await
+    }
+
+    /// Get list of available timezones as a resource
+    #[mcp_resource(
+        uri_template = "timedate://timezones/{filter}",
+        name = "timezone_list",
+        description = "List of available timezones, optionally filtered",
+        mime_type = "application/json"
+    )]
+    pub async fn timezone_list_resource(&self, filter: String) -> anyhow::Result<Vec<String>> {
+        let filter_option = if filter == "all" { None } else { Some(filter) };
+        self.list_timezones_internal(filter_option, None).await
+    }
+
+    /// Get at most `limit` timezones matching a filter as a resource
+    #[mcp_resource(
+        uri_template = "timedate://timezones/{filter}/{limit}",
+        name = "timezone_list_limited",
+        description = "List of available timezones, optionally filtered, capped at a maximum count",
+        mime_type = "application/json"
+    )]
+    pub async fn timezone_list_limited_resource(
+        &self,
+        filter: String,
+        limit: String,
+    ) -> anyhow::Result<Vec<String>> {
+        let limit: usize = limit
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Invalid limit: {}", limit))?;
+        let filter_option = if filter == "all" { None } else { Some(filter) };
+        self.list_timezones_internal(filter_option, Some(limit))
+            .await
+    }
+
+    /// Get the time at an instant as a resource. Both segments are percent-decoded, so zone
+    /// names pass their slash as "%2F"; a literal "+" in an offset is kept as is.
+    #[mcp_resource(
+        uri_template = "timedate://time-at/{timezone}/{datetime}",
+        name = "time_at",
+        description = "Time at a given date/time in the specified timezone",
+        mime_type = "application/json"
+    )]
+    pub async fn time_at_resource(
+        &self,
+        timezone: String,
+        datetime: String,
+    ) -> anyhow::Result<TimeInfo> {
+        let timezone = self.percent_decode(&timezone)?;
+        let datetime = self.percent_decode(&datetime)?;
+        let tz_option = if timezone == "local" {
+            None
+        } else {
+            Some(timezone)
+        };
+        let tz = self.timezone_or_default(tz_option.as_deref())?;
+        let dt = self.parse_flexible_datetime(&datetime, &tz)?;
+        Ok(self.format_time_info(dt))
+    }
+
+    /// Get the current time with the next boundary of a refresh interval, for live clocks
+    #[mcp_resource(
+        uri_template = "timedate://clock/{timezone}/{interval}",
+        name = "clock",
+        description = "Current time with the next interval boundary (e.g. interval 1s or 1m) and the milliseconds until it",
+        mime_type = "application/json"
+    )]
+    pub async fn clock_resource(
+        &self,
+        timezone: String,
+        interval: String,
+    ) -> anyhow::Result<ClockTick> {
+        let timezone = self.percent_decode(&timezone)?;
+        let interval = self.percent_decode(&interval)?;
+        let tz = if timezone == "local" {
+            self.default_timezone
+        } else {
+            self.parse_timezone(&timezone)?
+        };
+        let step = self.parse_duration_text(&interval)?.num_milliseconds();
+        if step <= 0 || step > 86_400_000 {
+            return Err(anyhow::anyhow!(
+                "interval must be positive and at most one day, got {}",
+                interval
+            ));
+        }
+
+        // Boundaries follow the local clock at the current offset, so an hourly tick in a
+        // +05:45 zone lands on the local hour
+        let now = self.now();
+        let offset_ms = i64::from(self.offset_seconds_at(&tz, &now)) * 1000;
+        let local_ms = now.timestamp_millis() + offset_ms;
+        let next_ms = (local_ms.div_euclid(step) + 1) * step - offset_ms;
+        let next = DateTime::from_timestamp_millis(next_ms)
+            .ok_or_else(|| TimeDateError::out_of_range("Time", next_ms))?;
+
+        Ok(ClockTick {
+            now: self.format_time_info(now.with_timezone(&tz)),
+            next_tick: self.format_time_info(next.with_timezone(&tz)),
+            millis_until_next_tick: next.signed_duration_since(now).num_milliseconds(),
+        })
+    }
+
+    /// Describe the server's configuration: version, default timezone, NTP server, accepted
+    /// input formats and tool names
+    #[mcp_resource(
+        uri_template = "timedate://capabilities",
+        name = "capabilities",
+        description = "Server version, default timezone, NTP server, accepted date formats and tools",
+        mime_type = "application/json"
+    )]
+    pub async fn capabilities_resource(&self) -> anyhow::Result<ServerCapabilities> {
+        Ok(ServerCapabilities {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            default_timezone: self.default_timezone.name().to_string(),
+            ntp_server: self.ntp_server.clone(),
+            input_formats: self.supported_formats().await?,
+            tools: self
+                .get_available_tools()
+                .into_iter()
+                .map(|tool| tool.name)
+                .collect(),
+        })
+    }
+
+    /// Get time format preferences as a resource
+    #[mcp_resource(
+        uri_template = "timedate://time-format",
+        name = "time_format",
+        description = "Time format preferences and current time in both formats",
+        mime_type = "application/json"
+    )]
+    pub async fn time_format_resource(&self) -> anyhow::Result<TimeFormatInfo> {
+        self.get_time_format_internal(None).await
+    }
+}
+
+// Resources now working with framework v0.9.1!
+
+// Internal implementation (for now we'll keep the improved architecture concept but use tools)
+impl TimeDateServer {
+    // Internal helper methods (moved from tools)
+    async fn get_current_time_internal(
+        &self,
+        timezone: Option<String>,
+    ) -> anyhow::Result<TimeInfo> {
+        let tz = self.timezone_or_default(timezone.as_deref())?;
+
+        let now = self.now().with_timezone(&tz);
+        Ok(self.format_time_info(now))
+    }
+
+    async fn get_timezone_info_internal(&self) -> anyhow::Result<TimezoneInfo> {
+        // Report the IANA name when it can be detected, falling back to the anonymous local offset
+        Ok(match self.detect_local_timezone() {
+            Some(tz) => self.timezone_info_at(tz.name(), &self.now().with_timezone(&tz)),
+            None => self.timezone_info_at("Local", &self.now().with_timezone(&Local)),
+        })
+    }
+
+    async fn list_timezones_internal(
+        &self,
+        filter: Option<String>,
+        limit: Option<usize>,
+    ) -> anyhow::Result<Vec<String>> {
+        if let Some(filter) = &filter {
+            limits::check_name("filter", filter)?;
+        }
+        let filter = filter.map(|f| f.to_lowercase());
+        let timezones: Vec<String> = self
+            .timezone_names()
+            .iter()
+            .filter(|(_, lowercase)| match &filter {
+                Some(f) => lowercase.contains(f.as_str()),
+                None => true,
+            })
+            .take(limit.unwrap_or(usize::MAX))
+            .map(|(name, _)| name.to_string())
+            .collect();
+
+        Ok(timezones)
+    }
+
+    async fn get_time_format_internal(
+        &self,
+        locale: Option<String>,
+    ) -> anyhow::Result<TimeFormatInfo> {
+        if let Some(locale) = &locale {
+            limits::check_name("locale", locale)?;
+        }
+        let now = self.now().with_timezone(&Local);
+
+        // An explicit locale wins; otherwise follow POSIX precedence (LC_ALL, LC_TIME, LANG),
+        // defaulting to 24h when nothing is set
+        let locale = locale.or_else(|| {
+            ["LC_ALL", "LC_TIME", "LANG"]
+                .iter()
+                .filter_map(|name| std::env::var(name).ok())
+                .find(|value| !value.trim().is_empty())
+        });
+        let is_12_hour = locale.as_deref().is_some_and(locales::uses_12_hour_clock);
+
+        Ok(TimeFormatInfo {
+            detected_format: if is_12_hour {
+                "12-hour".to_string()
+            } else {
+                "24-hour".to_string()
+            },
+            is_12_hour,
+            current_time_12h: now.format("%I:%M:%S %p").to_string(),
+            current_time_24h: now.format("%H:%M:%S").to_string(),
+        })
+    }
+}
+
+impl TimeDateServer {
+    fn parse_timezone(&self, name: &str) -> anyhow::Result<Tz> {
+        self.parse_timezone_as(name, "timezone")
+    }
+
+    /// Resolve a timezone name, abbreviation, Windows zone id or whole-hour offset (see
+    /// `match_timezone`); the error names what was being parsed (e.g. "source timezone") and
+    /// lists the candidates of an ambiguous alias or the closest valid names
+    fn parse_timezone_as(&self, name: &str, label: &'static str) -> anyhow::Result<Tz> {
+        limits::check_name(label, name)?;
+        match self.match_timezone(name) {
+            Some((_, zones)) if zones.len() == 1 => Ok(zones[0]),
+            Some((_, zones)) => Err(TimeDateError::AmbiguousTimezone {
+                label,
+                input: name.to_string(),
+                candidates: zones.iter().map(|tz| tz.name().to_string()).collect(),
+            }
+            .into()),
+            None => Err(self.unknown_timezone(name, label).into()),
+        }
+    }
+
+    /// Zones a name may stand for and where it was found: "iana" for database names (legacy ones
+    /// such as "EST" included), "abbreviation" or "windows" for the alias tables, "offset" for
+    /// "GMT+2" style offsets mapped to the matching Etc/GMT zone. More than one zone means an
+    /// ambiguous abbreviation.
+    fn match_timezone(&self, name: &str) -> Option<(&'static str, Vec<Tz>)> {
+        if let Ok(tz) = Tz::from_str(name) {
+            return Some(("iana", vec![tz]));
+        }
+        if let Some((kind, zones)) = aliases::lookup(name) {
+            let source = match kind {
+                aliases::AliasKind::Abbreviation => "abbreviation",
+                aliases::AliasKind::Windows => "windows",
+            };
+            let zones = zones
+                .iter()
+                .filter_map(|zone| Tz::from_str(zone).ok())
+                .collect();
+            return Some((source, zones));
+        }
+
+        // Etc/GMT names carry the inverted POSIX sign and exist for whole hours from -12 to +14
+        let offset = self.parse_offset(name).ok()?;
+        if offset % 3600 != 0 || !(-12 * 3600..=14 * 3600).contains(&offset) {
+            return None;
+        }
+        let zone = match offset / 3600 {
+            0 => "Etc/UTC".to_string(),
+            hours => format!("Etc/GMT{:+}", -hours),
+        };
+        Some(("offset", vec![Tz::from_str(&zone).ok()?]))
+    }
+
+    /// Error for a name no zone matches. Offsets without an Etc/GMT zone (e.g. "UTC+05:30")
+    /// suggest zones currently at that offset instead of similar names.
+    fn unknown_timezone(&self, name: &str, label: &'static str) -> TimeDateError {
+        let suggestions = match self.parse_offset(name) {
+            Ok(offset) => {
+                let now = self.now();
+                self.timezone_names()
+                    .iter()
+                    .filter(|(zone, _)| zone.contains('/') && !zone.starts_with("Etc/"))
+                    .filter_map(|(zone, _)| Tz::from_str(zone).ok())
+                    .filter(|tz| self.offset_seconds_at(tz, &now) == offset)
+                    .take(3)
+                    .map(|tz| tz.name().to_string())
+                    .collect()
+            }
+            Err(_) => self
+                .suggest_timezones(name, 3)
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+        };
+        TimeDateError::InvalidTimezone {
+            label,
+            input: name.to_string(),
+            suggestions,
+        }
+    }
+
+    /// Closest timezone names by edit distance, compared both to the full name and to the
+    /// city part so "Berln" still finds "Europe/Berlin"
+    fn suggest_timezones(&self, name: &str, count: usize) -> Vec<&'static str> {
+        let wanted = name.trim().to_lowercase();
+        if wanted.is_empty() {
+            return Vec::new();
+        }
+        let mut ranked: Vec<(usize, &'static str)> = TZ_VARIANTS
+            .iter()
+            .map(|tz| {
+                let candidate = tz.name().to_lowercase();
+                let city = candidate.rsplit('/').next().unwrap_or(&candidate);
+                let distance = self
+                    .edit_distance(&wanted, &candidate)
+                    .min(self.edit_distance(&wanted, city));
+                (distance, tz.name())
+            })
+            .filter(|(distance, _)| *distance <= wanted.chars().count().div_ceil(2))
+            .collect();
+        ranked.sort();
+        ranked
+            .into_iter()
+            .take(count)
+            .map(|(_, name)| name)
+            .collect()
+    }
+
+    /// Levenshtein distance between two strings, counted in characters
+    fn edit_distance(&self, a: &str, b: &str) -> usize {
+        let b: Vec<char> = b.chars().collect();
+        let mut previous: Vec<usize> = (0..=b.len()).collect();
+        for (i, a_char) in a.chars().enumerate() {
+            let mut current = vec![i + 1];
+            for (j, b_char) in b.iter().enumerate() {
+                let substitution = previous[j] + usize::from(a_char != *b_char);
+                current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+            }
+            previous = current;
+        }
+        previous[b.len()]
+    }
+
+    /// Resolve an optional timezone argument, defaulting to the server's default timezone
+    fn timezone_or_default(&self, timezone: Option<&str>) -> anyhow::Result<Tz> {
+        match timezone {
+            Some(tz_str) => self.parse_timezone(tz_str),
+            None => Ok(self.default_timezone),
+        }
+    }
+
+    /// Parse a date/time in any supported format, resolving ambiguous local times to the earlier
+    /// instant. See `parse_flexible_datetime_with` for the accepted formats.
+    fn parse_flexible_datetime(&self, input: &str, tz: &Tz) -> anyhow::Result<DateTime<Tz>> {
+        self.parse_flexible_datetime_with(input, tz, Disambiguation::Earliest)
+    }
+
+    /// Parse a date/time in any supported format, tried in priority order: "now", RFC3339,
+    /// RFC2822, other formats carrying an explicit offset, then naive date-times and dates
+    /// (read as local time in `tz`). Naive times in a DST gap are rejected; times repeated
+    /// when clocks fall back are resolved by `mode`.
+    fn parse_flexible_datetime_with(
+        &self,
+        input: &str,
+        tz: &Tz,
+        mode: Disambiguation,
+    ) -> anyhow::Result<DateTime<Tz>> {
+        self.detect_datetime_format(input, tz, mode)?
+            .map(|(dt, _)| dt)
+            .ok_or_else(|| TimeDateError::invalid_date_format(input.trim()).into())
+    }
+
+    /// Parse with the shared format list, also returning the name of the format that matched.
+    /// Returns `Ok(None)` when no format matches and an error when a naive time cannot be resolved.
+    /// A seconds field of 60 is rejected: chrono reads it as a leap second, which timestamps and
+    /// arithmetic elsewhere cannot represent. So are years outside `limits::MIN_YEAR..=MAX_YEAR`.
+    fn detect_datetime_format(
+        &self,
+        input: &str,
+        tz: &Tz,
+        mode: Disambiguation,
+    ) -> anyhow::Result<Option<(DateTime<Tz>, &'static str)>> {
+        limits::check_length("date/time", input)?;
+        let detected = self.match_datetime_format(input, tz, mode)?;
+        if let Some((dt, _)) = &detected {
+            limits::check_year(dt.year())?;
+            if dt.nanosecond() >= 1_000_000_000 {
+                return Err(TimeDateError::InvalidDateFormat {
+                    input: input.trim().to_string(),
+                    expected: Some("seconds from 00 to 59; leap seconds are not supported"),
+                }
+                .into());
+            }
+        }
+        Ok(detected)
+    }
+
+    fn match_datetime_format(
+        &self,
+        input: &str,
+        tz: &Tz,
+        mode: Disambiguation,
+    ) -> anyhow::Result<Option<(DateTime<Tz>, &'static str)>> {
+        let input = input.trim();
+        if input.eq_ignore_ascii_case("now") {
+            return Ok(Some((self.now().with_timezone(tz), "now")));
+        }
+        if let Ok(dt) = DateTime::parse_from_rfc3339(input) {
+            return Ok(Some((dt.with_timezone(tz), "rfc3339")));
+        }
+        if let Ok(dt) = DateTime::parse_from_rfc2822(input) {
+            return Ok(Some((dt.with_timezone(tz), "rfc2822")));
+        }
+        if let Some((dt, name)) = OFFSET_DATE_TIME_FORMATS
+            .iter()
+            .find_map(|(name, format, _)| {
+                DateTime::parse_from_str(input, format)
+                    .ok()
+                    .map(|dt| (dt, *name))
+            })
+        {
+            return Ok(Some((dt.with_timezone(tz), name)));
+        }
+
+        let naive = NAIVE_DATE_TIME_FORMATS
+            .iter()
+            .find_map(|(name, format, _)| {
+                NaiveDateTime::parse_from_str(input, format)
+                    .ok()
+                    .map(|naive| (naive, *name))
+            })
+            .or_else(|| {
+                NAIVE_DATE_FORMATS.iter().find_map(|(name, format, _)| {
+                    NaiveDate::parse_from_str(input, format)
+                        .ok()
+                        .map(|date| (date.and_time(NaiveTime::MIN), *name))
+                })
+            });
+        let Some((naive, name)) = naive else {
+            return Ok(None);
+        };
+
+        let dt = match tz.from_local_datetime(&naive) {
+            LocalResult::Single(dt) => dt,
+            LocalResult::Ambiguous(earliest, latest) => match mode {
+                Disambiguation::Earliest => earliest,
+                Disambiguation::Latest => latest,
+                Disambiguation::Reject => {
+                    return Err(TimeDateError::AmbiguousLocalTime {
+                        input: input.to_string(),
+                        timezone: tz.name().to_string(),
+                        earliest: earliest.to_rfc3339(),
+                        latest: latest.to_rfc3339(),
+                    }
+                    .into())
+                }
+            },
+            LocalResult::None => {
+                // chrono-tz also finds no reading when the instant would lie outside chrono's range
+                let offset = self.utc_offset_near(tz, naive);
+                if naive.checked_sub_signed(offset).is_none() {
+                    return Err(TimeDateError::out_of_range("Date", input).into());
+                }
+                return Err(TimeDateError::NonexistentLocalTime {
+                    input: input.to_string(),
+                    timezone: tz.name().to_string(),
+                }
+                .into());
+            }
+        };
+        Ok(Some((dt, name)))
+    }
+
+    /// Every format the shared parser accepts, in the order they are tried
+    fn known_formats(
+        &self,
+    ) -> impl Iterator<Item = &'static (&'static str, &'static str, &'static str)> {
+        BUILTIN_DATE_TIME_FORMATS
+            .iter()
+            .chain(OFFSET_DATE_TIME_FORMATS)
+            .chain(NAIVE_DATE_TIME_FORMATS)
+            .chain(NAIVE_DATE_FORMATS)
+    }
+
+    /// Parse a disambiguation mode: "earliest" (default), "latest" or "reject"
+    fn parse_disambiguation(&self, mode: Option<&str>) -> anyhow::Result<Disambiguation> {
+        match mode.map(|mode| mode.trim().to_ascii_lowercase()).as_deref() {
+            None | Some("earliest") => Ok(Disambiguation::Earliest),
+            Some("latest") => Ok(Disambiguation::Latest),
+            Some("reject") => Ok(Disambiguation::Reject),
+            Some(other) => Err(anyhow::anyhow!(
+                "Invalid disambiguate value: {} (expected earliest, latest or reject)",
+                other
+            )),
+        }
+    }
+
+    /// Offset of `tz` at `naive` read as a UTC time, close enough to tell whether the local time
+    /// can map to an instant at all
+    fn utc_offset_near(&self, tz: &Tz, naive: NaiveDateTime) -> chrono::Duration {
+        let seconds = tz.offset_from_utc_datetime(&naive).fix().local_minus_utc();
+        chrono::Duration::seconds(i64::from(seconds))
+    }
+
+    /// UTC offset of `tz` in seconds at the given instant
+    fn offset_seconds_at<T: TimeZone>(&self, tz: &Tz, instant: &DateTime<T>) -> i32 {
+        tz.offset_from_utc_datetime(&instant.naive_utc())
+            .fix()
+            .local_minus_utc()
+    }
+
+    /// Parse a wall-clock time such as "15:30", "09:30:15", "3 PM" or "3:30 p.m.",
+    /// also reporting whether it carried an AM/PM marker
+    fn parse_clock_sample(&self, input: &str) -> anyhow::Result<(NaiveTime, bool)> {
+        let invalid = || anyhow::anyhow!("Invalid time of day: {}", input);
+        let normalized = input.trim().to_lowercase().replace('.', "");
+
+        let (clock, meridiem) = if let Some(rest) = normalized.strip_suffix("am") {
+            (rest.trim(), Some(false))
+        } else if let Some(rest) = normalized.strip_suffix("pm") {
+            (rest.trim(), Some(true))
+        } else {
+            (normalized.as_str(), None)
+        };
+
+        // A bare hour ("3 PM") is only accepted together with an AM/PM marker
+        if !clock.contains(':') && meridiem.is_none() {
+            return Err(invalid());
+        }
+        let fields = clock
+            .split(':')
+            .map(|part| match part.len() {
+                1 | 2 => part.parse::<u32>().map_err(|_| invalid()),
+                _ => Err(invalid()),
+            })
+            .collect::<anyhow::Result<Vec<u32>>>()?;
+        let (hour, minute, second) = match fields[..] {
+            [h] => (h, 0, 0),
+            [h, m] => (h, m, 0),
+            [h, m, s] => (h, m, s),
+            _ => return Err(invalid()),
+        };
+
+        let hour = match meridiem {
+            Some(is_pm) => {
+                if !(1..=12).contains(&hour) {
+                    return Err(invalid());
+                }
+                (hour % 12) + if is_pm { 12 } else { 0 }
+            }
+            None => hour,
+        };
+
+        NaiveTime::from_hms_opt(hour, minute, second)
+            .map(|time| (time, meridiem.is_some()))
+            .ok_or_else(invalid)
+    }
+
+    /// First instant of a local calendar day, skipping forward if midnight falls in a DST gap
+    fn start_of_local_day(&self, tz: &Tz, date: NaiveDate) -> anyhow::Result<DateTime<Tz>> {
+        self.resolve_local_time(tz, date.and_time(NaiveTime::MIN))
+    }
 
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
-pub struct TimeFormatInfo {
-    pub detected_format: String,
-    pub is_12_hour: bool,
-    pub current_time_12h: String,
-    pub current_time_24h: String,
-}
+    /// Map a local wall-clock time to an instant, taking the earlier reading of an ambiguous time
+    /// and the first valid time after a DST gap
+    fn resolve_local_time(&self, tz: &Tz, local: NaiveDateTime) -> anyhow::Result<DateTime<Tz>> {
+        (0..=24 * 60)
+            .step_by(15)
+            .find_map(|minutes| {
+                let shifted = local.checked_add_signed(chrono::Duration::minutes(minutes))?;
+                tz.from_local_datetime(&shifted).earliest()
+            })
+            .ok_or_else(|| {
+                anyhow::anyhow!("Could not resolve local time {} in {}", local, tz.name())
+            })
+    }
 
-/// TimeDate MCP Server - Time and Date Operations with Timezone Support
-#[mcp_server(
-    name = "TimeDate MCP Server",
-    version = "0.4.0",
-    description = "A Model Context Protocol server for time and date operations with timezone support with parameterized resources",
-    auth = "disabled"
-)]
-#[derive(Default, Clone)]
-pub struct TimeDateServer;
+    fn parse_weekday(&self, name: &str) -> anyhow::Result<Weekday> {
+        Weekday::from_str(name.trim()).map_err(|_| anyhow::anyhow!("Invalid weekday: {}", name))
+    }
 
-#[mcp_tools]
-impl TimeDateServer {
-    /// Get time at a specific date and timezone
-    pub async fn get_time_at(
+    fn weekday_name(&self, day: Weekday) -> &'static str {
+        match day {
+            Weekday::Mon => "Monday",
+            Weekday::Tue => "Tuesday",
+            Weekday::Wed => "Wednesday",
+            Weekday::Thu => "Thursday",
+            Weekday::Fri => "Friday",
+            Weekday::Sat => "Saturday",
+            Weekday::Sun => "Sunday",
+        }
+    }
+
+    /// Parse a list of weekday names, defaulting to Monday through Friday
+    fn parse_working_days(
         &self,
-        date_time: String,
-        timezone: Option<String>,
-    ) -> anyhow::Result<TimeInfo> {
-        let tz = match timezone {
-            Some(tz_str) => Tz::from_str(&tz_str)
-                .map_err(|_| anyhow::anyhow!("Invalid timezone: {}", tz_str))?,
-            None => chrono_tz::UTC,
-        };
+        working_days: Option<Vec<String>>,
+    ) -> anyhow::Result<Vec<Weekday>> {
+        match working_days {
+            Some(days) => {
+                limits::check_list_len("working_days", days.len())?;
+                days.iter().map(|day| self.parse_weekday(day)).collect()
+            }
+            None => Ok(vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ]),
+        }
+    }
 
-        let dt = chrono::DateTime::parse_from_rfc3339(&date_time)
-            .or_else(|_| chrono::DateTime::parse_from_str(&date_time, "%Y-%m-%d %H:%M:%S"))
-            .or_else(|_| chrono::DateTime::parse_from_str(&date_time, "%Y-%m-%d"))
-            .map_err(|_| anyhow::anyhow!("Invalid date format: {}", date_time))?
-            .with_timezone(&tz);
+    /// Validate a same-day `[start_hour, end_hour)` window
+    fn validate_hour_window(&self, start_hour: u32, end_hour: u32) -> anyhow::Result<()> {
+        if start_hour >= end_hour || end_hour > 24 {
+            return Err(anyhow::anyhow!(
+                "Invalid hour window {}-{}: start must be before end and end at most 24",
+                start_hour,
+                end_hour
+            ));
+        }
+        Ok(())
+    }
 
-        Ok(self.format_time_info(dt))
+    /// Parse a calendar date "YYYY-MM-DD"
+    fn parse_date(&self, input: &str) -> anyhow::Result<NaiveDate> {
+        limits::check_length("date", input)?;
+        let date = NaiveDate::parse_from_str(input.trim(), "%Y-%m-%d").map_err(|_| {
+            TimeDateError::InvalidDateFormat {
+                input: input.to_string(),
+                expected: Some("YYYY-MM-DD"),
+            }
+        })?;
+        limits::check_year(date.year())?;
+        Ok(date)
     }
 
-    /// Calculate time with offset (add/subtract time)
-    pub async fn calculate_time_offset(
+    fn validate_coordinates(&self, latitude: f64, longitude: f64) -> anyhow::Result<()> {
+        if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+            return Err(anyhow::anyhow!(
+                "Invalid coordinates ({}, {}): latitude must be within ±90 and longitude within ±180",
+                latitude,
+                longitude
+            ));
+        }
+        Ok(())
+    }
+
+    /// Compute sun times for a local date at the given coordinates, rendered in `tz`
+    fn sun_times_for(
         &self,
-        base_time: String,
-        offset_hours: i32,
-        timezone: Option<String>,
-    ) -> anyhow::Result<TimeInfo> {
-        let tz = match timezone {
-            Some(tz_str) => Tz::from_str(&tz_str)
-                .map_err(|_| anyhow::anyhow!("Invalid timezone: {}", tz_str))?,
-            None => chrono_tz::UTC,
+        date: NaiveDate,
+        latitude: f64,
+        longitude: f64,
+        tz: &Tz,
+    ) -> anyhow::Result<SunTimes> {
+        self.validate_coordinates(latitude, longitude)?;
+        let day = solar::solar_day(date, latitude, longitude);
+        let utc_midnight = date.and_time(NaiveTime::MIN).and_utc();
+        let at_minutes = |minutes: f64| -> anyhow::Result<String> {
+            Ok(utc_midnight
+                .checked_add_signed(chrono::Duration::milliseconds(
+                    (minutes * 60_000.0).round() as i64
+                ))
+                .ok_or_else(|| TimeDateError::out_of_range("Date", date))?
+                .with_timezone(tz)
+                .to_rfc3339())
         };
 
-        let base_dt = if base_time.to_lowercase() == "now" {
-            Utc::now().with_timezone(&tz)
+        Ok(SunTimes {
+            date: date.to_string(),
+            latitude,
+            longitude,
+            timezone: tz.name().to_string(),
+            status: day.status.as_str().to_string(),
+            sunrise: day.sunrise_minutes.map(at_minutes).transpose()?,
+            sunset: day.sunset_minutes.map(at_minutes).transpose()?,
+            solar_noon: at_minutes(day.solar_noon_minutes)?,
+            day_length_seconds: (day.day_length_minutes() * 60.0).round() as i64,
+        })
+    }
+
+    fn days_in_month(&self, year: i32, month: u32) -> u32 {
+        let (next_year, next_month) = if month == 12 {
+            (year + 1, 1)
         } else {
-            chrono::DateTime::parse_from_rfc3339(&base_time)
-                .or_else(|_| chrono::DateTime::parse_from_str(&base_time, "%Y-%m-%d %H:%M:%S"))
-                .map_err(|_| anyhow::anyhow!("Invalid date format: {}", base_time))?
-                .with_timezone(&tz)
+            (year, month + 1)
+        };
+        NaiveDate::from_ymd_opt(next_year, next_month, 1)
+            .and_then(|first| first.pred_opt())
+            .map_or(31, |last| last.day())
+    }
+
+    /// Shift a time by `amount` of a unit. Days and larger move the local wall clock
+    /// (so "1 day" across a DST change keeps the same clock time); smaller units are exact.
+    fn shift_by_unit(
+        &self,
+        dt: DateTime<Tz>,
+        amount: i64,
+        unit: TimeUnit,
+    ) -> anyhow::Result<DateTime<Tz>> {
+        let out_of_range = || TimeDateError::out_of_range("Result", format!("{amount} {unit}"));
+        let exact = |seconds_per_unit: i64| -> anyhow::Result<DateTime<Tz>> {
+            let offset = amount
+                .checked_mul(seconds_per_unit)
+                .and_then(chrono::Duration::try_seconds)
+                .ok_or_else(out_of_range)?;
+            Ok(dt.checked_add_signed(offset).ok_or_else(out_of_range)?)
+        };
+        let months = |months_per_unit: i64| -> anyhow::Result<DateTime<Tz>> {
+            let total = amount
+                .checked_mul(months_per_unit)
+                .and_then(|m| i32::try_from(m).ok())
+                .ok_or_else(out_of_range)?;
+            let local = dt.naive_local();
+            let shifted = if total >= 0 {
+                local.checked_add_months(chrono::Months::new(total.unsigned_abs()))
+            } else {
+                local.checked_sub_months(chrono::Months::new(total.unsigned_abs()))
+            }
+            .ok_or_else(out_of_range)?;
+            self.resolve_local_time(&dt.timezone(), shifted)
+        };
+        let days = |days_per_unit: i64| -> anyhow::Result<DateTime<Tz>> {
+            let offset = amount
+                .checked_mul(days_per_unit)
+                .and_then(chrono::Duration::try_days)
+                .ok_or_else(out_of_range)?;
+            let shifted = dt
+                .naive_local()
+                .checked_add_signed(offset)
+                .ok_or_else(out_of_range)?;
+            self.resolve_local_time(&dt.timezone(), shifted)
         };
 
-        let result_dt = base_dt + chrono::Duration::hours(offset_hours as i64);
-        Ok(self.format_time_info(result_dt))
+        match unit {
+            TimeUnit::Second => exact(1),
+            TimeUnit::Minute => exact(60),
+            TimeUnit::Hour => exact(3600),
+            TimeUnit::Day => days(1),
+            TimeUnit::Week => days(7),
+            TimeUnit::Month => months(1),
+            TimeUnit::Year => months(12),
+        }
     }
 
-    /// Convert time between timezones
-    pub async fn convert_timezone(
+    /// Expand a weekly recurrence into UTC `(start, end)` intervals between two local dates
+    fn expand_weekly(
         &self,
-        time: String,
-        from_timezone: String,
-        to_timezone: String,
-    ) -> anyhow::Result<TimeInfo> {
-        let from_tz = Tz::from_str(&from_timezone)
-            .map_err(|_| anyhow::anyhow!("Invalid source timezone: {}", from_timezone))?;
+        spec: &RecurrenceSpec,
+        from: NaiveDate,
+        until: NaiveDate,
+    ) -> anyhow::Result<Vec<(DateTime<Utc>, DateTime<Utc>)>> {
+        let tz = self.parse_timezone(&spec.timezone)?;
+        let weekday = self.parse_weekday(&spec.weekday)?;
+        let (time, _) = self.parse_clock_sample(&spec.time)?;
+        let duration = chrono::Duration::minutes(i64::from(spec.duration_minutes));
+
+        from.iter_days()
+            .take_while(|date| *date <= until)
+            .filter(|date| date.weekday() == weekday)
+            .map(|date| {
+                let start = self
+                    .resolve_local_time(&tz, date.and_time(time))?
+                    .with_timezone(&Utc);
+                Ok((start, start + duration))
+            })
+            .collect()
+    }
 
-        let to_tz = Tz::from_str(&to_timezone)
-            .map_err(|_| anyhow::anyhow!("Invalid target timezone: {}", to_timezone))?;
+    /// Signed seconds (with millisecond precision) from `start` to `end`
+    fn elapsed_seconds(&self, start: &str, end: &str) -> anyhow::Result<f64> {
+        let start_dt = self.parse_flexible_datetime(start, &chrono_tz::UTC)?;
+        let end_dt = self.parse_flexible_datetime(end, &chrono_tz::UTC)?;
+        Ok(end_dt.signed_duration_since(start_dt).num_milliseconds() as f64 / 1000.0)
+    }
 
-        let dt = if time.to_lowercase() == "now" {
-            Utc::now().with_timezone(&from_tz)
-        } else {
-            chrono::DateTime::parse_from_rfc3339(&time)
-                .or_else(|_| chrono::DateTime::parse_from_str(&time, "%Y-%m-%d %H:%M:%S"))
-                .map_err(|_| anyhow::anyhow!("Invalid time format: {}", time))?
-                .with_timezone(&from_tz)
+    /// Most recent date on or before `date` that falls on `week_start`
+    fn start_of_week(&self, date: NaiveDate, week_start: Weekday) -> NaiveDate {
+        let days_back =
+            (7 + date.weekday().num_days_from_monday() - week_start.num_days_from_monday()) % 7;
+        date - chrono::Duration::days(i64::from(days_back))
+    }
+
+    /// Convert a Julian Day (UT) to a UTC instant, rounded to the second
+    fn julian_day_to_utc(&self, julian_day: f64) -> anyhow::Result<DateTime<Utc>> {
+        let unix_seconds = ((julian_day - 2440587.5) * 86400.0).round();
+        if !unix_seconds.is_finite() || unix_seconds.abs() > i64::MAX as f64 {
+            return Err(TimeDateError::out_of_range("Julian day", julian_day).into());
+        }
+        DateTime::from_timestamp(unix_seconds as i64, 0)
+            .ok_or_else(|| TimeDateError::out_of_range("Julian day", julian_day).into())
+    }
+
+    fn duration_info(&self, duration: chrono::Duration) -> DurationInfo {
+        let total_seconds = duration.num_seconds();
+        let magnitude = total_seconds.unsigned_abs() as i64;
+        let (days, hours, minutes, seconds) = (
+            magnitude / 86400,
+            magnitude % 86400 / 3600,
+            magnitude % 3600 / 60,
+            magnitude % 60,
+        );
+
+        let mut iso8601 = String::from(if total_seconds < 0 { "-P" } else { "P" });
+        if days > 0 {
+            iso8601.push_str(&format!("{days}D"));
+        }
+        if hours > 0 || minutes > 0 || seconds > 0 || days == 0 {
+            iso8601.push('T');
+            if hours > 0 {
+                iso8601.push_str(&format!("{hours}H"));
+            }
+            if minutes > 0 {
+                iso8601.push_str(&format!("{minutes}M"));
+            }
+            if seconds > 0 || magnitude == 0 {
+                iso8601.push_str(&format!("{seconds}S"));
+            }
+        }
+
+        let parts: Vec<String> = [
+            (days, "day"),
+            (hours, "hour"),
+            (minutes, "minute"),
+            (seconds, "second"),
+        ]
+        .iter()
+        .filter(|(value, _)| *value > 0)
+        .map(|(value, unit)| self.pluralize(*value, unit))
+        .collect();
+        let human_readable = match (parts.is_empty(), total_seconds < 0) {
+            (true, _) => "0 seconds".to_string(),
+            (false, true) => format!("-{}", parts.join(", ")),
+            (false, false) => parts.join(", "),
         };
 
-        let converted = dt.with_timezone(&to_tz);
-        Ok(self.format_time_info(converted))
+        DurationInfo {
+            total_seconds,
+            is_negative: total_seconds < 0,
+            days,
+            hours,
+            minutes,
+            seconds,
+            iso8601,
+            human_readable,
+        }
     }
 
-    /// Get current time in local timezone (exposed as a tool for now)
-    pub async fn get_current_time(&self, timezone: Option<String>) -> anyhow::Result<TimeInfo> {
-        self.get_current_time_internal(timezone).await
+    fn pluralize(&self, value: i64, unit: &str) -> String {
+        if value == 1 {
+            format!("{value} {unit}")
+        } else {
+            format!("{value} {unit}s")
+        }
     }
 
-    /// Get current timezone information
-    pub async fn get_timezone_info(&self) -> anyhow::Result<TimezoneInfo> {
-        let now = Local::now();
-        let _offset = now.offset();
+    /// Format an offset in seconds as "+HH:MM"
+    fn format_offset(&self, offset_seconds: i32) -> String {
+        let sign = if offset_seconds < 0 { '-' } else { '+' };
+        let minutes = offset_seconds.unsigned_abs() / 60;
+        format!("{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+    }
 
-        Ok(TimezoneInfo {
-            name: "Local".to_string(), // Local timezone doesn't expose name directly
-            current_time: now.format("%Y-%m-%d %H:%M:%S %Z").to_string(),
-            utc_offset: now.format("%z").to_string(),
-            is_dst: false, // Can't easily determine DST for local timezone
+    /// Abbreviation for an offset, falling back to the numeric form for zones without one
+    fn offset_abbreviation(&self, offset: &chrono_tz::TzOffset) -> String {
+        offset
+            .abbreviation()
+            .map(str::to_string)
+            .unwrap_or_else(|| self.format_offset(offset.fix().local_minus_utc()))
+    }
+
+    /// Describe the offset change at `instant`, or None if it does not involve DST (e.g. a zone
+    /// moving to a new standard offset)
+    fn dst_transition_at(&self, tz: &Tz, instant: DateTime<Utc>) -> Option<DstTransition> {
+        let before =
+            tz.offset_from_utc_datetime(&(instant - chrono::Duration::seconds(1)).naive_utc());
+        let after = tz.offset_from_utc_datetime(&instant.naive_utc());
+        if before.dst_offset().is_zero() && after.dst_offset().is_zero() {
+            return None;
+        }
+        let before_seconds = before.fix().local_minus_utc();
+        let after_seconds = after.fix().local_minus_utc();
+        Some(DstTransition {
+            kind: if after_seconds > before_seconds {
+                "spring_forward"
+            } else {
+                "fall_back"
+            }
+            .to_string(),
+            instant: instant.to_rfc3339_opts(SecondsFormat::Secs, true),
+            offset_before: self.format_offset(before_seconds),
+            offset_after: self.format_offset(after_seconds),
         })
     }
 
-    /// Get time format preference information
-    pub async fn get_time_format(&self) -> anyhow::Result<TimeFormatInfo> {
-        self.get_time_format_internal().await
+    /// First and last instant of a UTC calendar year
+    fn utc_year_bounds(&self, year: i32) -> anyhow::Result<(DateTime<Utc>, DateTime<Utc>)> {
+        limits::check_year(year)?;
+        let start = NaiveDate::from_ymd_opt(year, 1, 1)
+            .ok_or_else(|| TimeDateError::out_of_range("Year", year))?;
+        let end = NaiveDate::from_ymd_opt(year + 1, 1, 1)
+            .ok_or_else(|| TimeDateError::out_of_range("Year", year))?;
+        Ok((
+            start.and_time(NaiveTime::MIN).and_utc(),
+            end.and_time(NaiveTime::MIN).and_utc(),
+        ))
     }
 
-    /// List available timezones
-    pub async fn list_timezones(&self, filter: Option<String>) -> anyhow::Result<Vec<String>> {
-        self.list_timezones_internal(filter).await
+    /// Find every UTC offset change of `tz` in `[start, end)`, each located to the exact second
+    fn find_transitions(
+        &self,
+        tz: &Tz,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Vec<DateTime<Utc>> {
+        let offset_at = |instant: DateTime<Utc>| {
+            tz.offset_from_utc_datetime(&instant.naive_utc())
+                .fix()
+                .local_minus_utc()
+        };
+        let step = chrono::Duration::hours(1);
+        let mut transitions = Vec::new();
+        let mut previous = start;
+        let mut previous_offset = offset_at(start);
+
+        while previous < end {
+            let next = (previous + step).min(end);
+            let next_offset = offset_at(next);
+            if next_offset != previous_offset {
+                // Binary search for the first second carrying the new offset
+                let (mut low, mut high) = (previous, next);
+                while high.signed_duration_since(low) > chrono::Duration::seconds(1) {
+                    let mid = low + high.signed_duration_since(low) / 2;
+                    if offset_at(mid) == previous_offset {
+                        low = mid;
+                    } else {
+                        high = mid;
+                    }
+                }
+                transitions.push(high);
+            }
+            previous = next;
+            previous_offset = next_offset;
+        }
+
+        transitions
     }
 
-    // Resources - Read-only data accessible via MCP resource URIs
+    /// Parse a UTC offset like "+05:30", "-0800", "+9", "UTC+2" or "Z" into seconds east of UTC
+    fn parse_offset(&self, input: &str) -> anyhow::Result<i32> {
+        limits::check_length("UTC offset", input)?;
+        let invalid = || anyhow::anyhow!("Invalid UTC offset: {}", input);
+        let trimmed = input.trim();
+        let upper = trimmed.to_ascii_uppercase();
+        let rest = upper
+            .strip_prefix("UTC")
+            .or_else(|| upper.strip_prefix("GMT"))
+            .unwrap_or(&upper);
+        if rest.is_empty() || rest == "Z" {
+            return Ok(0);
+        }
 
-    /// Get current time as a resource
-    #[mcp_resource(
-        uri_template = "timedate://current-time/{timezone}",
-        name = "current_time",
-        description = "Current time in the specified timezone",
-        mime_type = "application/json"
-    )]
-    pub async fn current_time_resource(&self, timezone: String) -> anyhow::Result<TimeInfo> {
-        let tz_option = if timezone == "local" {
-            None
+        let (sign, digits) = if let Some(digits) = rest.strip_prefix('+') {
+            (1, digits)
+        } else if let Some(digits) = rest.strip_prefix(['-', '\u{2212}']) {
+            (-1, digits)
         } else {
-            Some(timezone)
+            return Err(invalid());
         };
-        self.get_current_time_internal(tz_option).await
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit() || c == ':') {
+            return Err(invalid());
+        }
+        let (hours, minutes) = match digits.split_once(':') {
+            Some((hours, minutes)) => (hours, minutes),
+            None if digits.len() > 2 => digits.split_at(digits.len() - 2),
+            None => (digits, "0"),
+        };
+        let hours: i32 = hours.parse().map_err(|_| invalid())?;
+        let minutes: i32 = minutes.parse().map_err(|_| invalid())?;
+        if hours > 18 || minutes >= 60 || (hours == 18 && minutes > 0) {
+            return Err(invalid());
+        }
+        Ok(sign * (hours * 3600 + minutes * 60))
     }
 
-    /// Get timezone information as a resource
-    #[mcp_resource(
-        uri_template = "timedate://timezone-info",
-        name = "timezone_info",
-        description = "Information about the local timezone",
-        mime_type = "application/json"
-    )]
-    pub async fn timezone_info_resource(&self) -> anyhow::Result<TimezoneInfo> {
-        let now = Local::now();
+    /// The nth `weekday` of the month containing `date`; negative `n` counts back from the month's end
+    fn nth_weekday_of_month(&self, date: NaiveDate, weekday: Weekday, n: i32) -> Option<NaiveDate> {
+        let (year, month) = (date.year(), date.month());
+        if n > 0 {
+            return NaiveDate::from_weekday_of_month_opt(
+                year,
+                month,
+                weekday,
+                u8::try_from(n).ok()?,
+            );
+        }
+        let last = NaiveDate::from_ymd_opt(year, month, self.days_in_month(year, month))?;
+        let back_to_weekday =
+            (last.weekday().num_days_from_monday() + 7 - weekday.num_days_from_monday()) % 7;
+        let candidate =
+            last - chrono::Duration::days(i64::from(back_to_weekday) + 7 * i64::from(-n - 1));
+        (candidate.month() == month).then_some(candidate)
+    }
 
-        Ok(TimezoneInfo {
-            name: "Local".to_string(),
-            current_time: now.format("%Y-%m-%d %H:%M:%S %Z").to_string(),
-            utc_offset: now.format("%z").to_string(),
-            is_dst: false,
-        })
+    /// Anniversary of `date` in `year`, observed on Feb 28 when a Feb 29 date falls in a common year
+    fn anniversary_in(&self, date: NaiveDate, year: i32) -> anyhow::Result<NaiveDate> {
+        date.with_year(year)
+            .or_else(|| NaiveDate::from_ymd_opt(year, 2, 28))
+            .ok_or_else(|| TimeDateError::out_of_range("Year", year).into())
     }
 
-    /// Get list of available timezones as a resource
-    #[mcp_resource(
-        uri_template = "timedate://timezones/{filter}",
-        name = "timezone_list",
-        description = "List of available timezones, optionally filtered",
-        mime_type = "application/json"
-    )]
-    pub async fn timezone_list_resource(&self, filter: String) -> anyhow::Result<Vec<String>> {
-        let filter_option = if filter == "all" { None } else { Some(filter) };
-        self.list_timezones_internal(filter_option).await
+    /// Parse a duration string, detecting ISO 8601, compact ("1h 30m") or colon ("H:MM:SS") notation
+    fn parse_duration_text(&self, input: &str) -> anyhow::Result<chrono::Duration> {
+        limits::check_length("duration", input)?;
+        let trimmed = input.trim();
+        let (negative, body) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest.trim_start()),
+            None => (false, trimmed),
+        };
+
+        let seconds = if let Some(iso) = body.strip_prefix(['P', 'p']) {
+            self.iso_duration_seconds(input, iso)?
+        } else if body.contains(':') {
+            self.colon_duration_seconds(input, body)?
+        } else {
+            self.duration_terms(input, body)?
+                .into_iter()
+                .map(|(value, unit)| {
+                    let scale = match unit.to_ascii_lowercase().as_str() {
+                        "w" | "wk" | "wks" | "week" | "weeks" => 604_800.0,
+                        "d" | "day" | "days" => 86_400.0,
+                        "h" | "hr" | "hrs" | "hour" | "hours" => 3_600.0,
+                        "m" | "min" | "mins" | "minute" | "minutes" => 60.0,
+                        "s" | "sec" | "secs" | "second" | "seconds" => 1.0,
+                        "ms" => 0.001,
+                        _ => {
+                            return Err(anyhow::anyhow!(
+                                "Unrecognized duration unit '{}' in {}",
+                                unit,
+                                input
+                            ))
+                        }
+                    };
+                    Ok(value * scale)
+                })
+                .sum::<anyhow::Result<f64>>()?
+        };
+
+        let millis = (seconds * 1000.0).round();
+        if !millis.is_finite() || millis.abs() > i64::MAX as f64 / 1_000_000.0 {
+            return Err(TimeDateError::out_of_range("Duration", input).into());
+        }
+        let duration = chrono::Duration::milliseconds(millis as i64);
+        Ok(if negative { -duration } else { duration })
     }
 
-    /// Get time format preferences as a resource
-    #[mcp_resource(
-        uri_template = "timedate://time-format",
-        name = "time_format",
-        description = "Time format preferences and current time in both formats",
-        mime_type = "application/json"
-    )]
-    pub async fn time_format_resource(&self) -> anyhow::Result<TimeFormatInfo> {
-        self.get_time_format_internal().await
+    /// Seconds in the body of an ISO 8601 duration (the part after "P")
+    fn iso_duration_seconds(&self, input: &str, body: &str) -> anyhow::Result<f64> {
+        let (date_part, time_part) = match body.find(['T', 't']) {
+            Some(index) => (&body[..index], Some(&body[index + 1..])),
+            None => (body, None),
+        };
+        if date_part.is_empty() && time_part.is_none_or(str::is_empty) {
+            return Err(anyhow::anyhow!("Empty ISO 8601 duration: {}", input));
+        }
+
+        let mut seconds = 0.0;
+        for (value, unit) in self.duration_terms(input, date_part)? {
+            seconds += value
+                * match unit.to_ascii_uppercase().as_str() {
+                    "W" => 604_800.0,
+                    "D" => 86_400.0,
+                    "Y" | "M" => {
+                        return Err(anyhow::anyhow!(
+                            "Ambiguous duration {}: years and months have no fixed length",
+                            input
+                        ))
+                    }
+                    _ => return Err(anyhow::anyhow!("Invalid ISO 8601 duration: {}", input)),
+                };
+        }
+        for (value, unit) in self.duration_terms(input, time_part.unwrap_or(""))? {
+            seconds += value
+                * match unit.to_ascii_uppercase().as_str() {
+                    "H" => 3_600.0,
+                    "M" => 60.0,
+                    "S" => 1.0,
+                    _ => return Err(anyhow::anyhow!("Invalid ISO 8601 duration: {}", input)),
+                };
+        }
+        Ok(seconds)
     }
-}
 
-// Resources now working with framework v0.9.1!
+    /// Seconds in an "H:MM:SS" duration; two-field forms are rejected as ambiguous
+    fn colon_duration_seconds(&self, input: &str, body: &str) -> anyhow::Result<f64> {
+        let fields: Vec<&str> = body.split(':').collect();
+        if fields.len() == 2 {
+            return Err(anyhow::anyhow!(
+                "Ambiguous duration {}: use H:MM:SS to distinguish hours from minutes",
+                input
+            ));
+        }
+        let invalid = || anyhow::anyhow!("Invalid H:MM:SS duration: {}", input);
+        let [hours, minutes, seconds] = fields[..] else {
+            return Err(invalid());
+        };
+        let hours: u64 = hours.parse().map_err(|_| invalid())?;
+        let minutes: u64 = minutes.parse().map_err(|_| invalid())?;
+        let seconds: f64 = seconds.parse().map_err(|_| invalid())?;
+        if minutes >= 60 || !(0.0..60.0).contains(&seconds) {
+            return Err(invalid());
+        }
+        Ok(hours as f64 * 3_600.0 + minutes as f64 * 60.0 + seconds)
+    }
 
-// Internal implementation (for now we'll keep the improved architecture concept but use tools)
-impl TimeDateServer {
-    // Internal helper methods (moved from tools)
-    async fn get_current_time_internal(
-        &self,
-        timezone: Option<String>,
-    ) -> anyhow::Result<TimeInfo> {
-        let tz = match timezone {
-            Some(tz_str) => Tz::from_str(&tz_str)
-                .map_err(|_| anyhow::anyhow!("Invalid timezone: {}", tz_str))?,
-            None => chrono_tz::UTC, // Default to UTC if no timezone specified
+    /// Split text like "1h 30m" or "1H30M" into (number, unit) terms
+    fn duration_terms(&self, input: &str, text: &str) -> anyhow::Result<Vec<(f64, String)>> {
+        let invalid = || anyhow::anyhow!("Invalid duration: {}", input);
+        let mut terms = Vec::new();
+        let mut chars = text.chars().filter(|c| !c.is_whitespace()).peekable();
+        while chars.peek().is_some() {
+            let mut number = String::new();
+            while let Some(c) = chars.next_if(|c| c.is_ascii_digit() || *c == '.' || *c == ',') {
+                number.push(if c == ',' { '.' } else { c });
+            }
+            let mut unit = String::new();
+            while let Some(c) = chars.next_if(|c| c.is_alphabetic()) {
+                unit.push(c);
+            }
+            if number.is_empty() || unit.is_empty() {
+                return Err(invalid());
+            }
+            terms.push((number.parse().map_err(|_| invalid())?, unit));
+        }
+        Ok(terms)
+    }
+
+    /// Whether daylight saving time is in effect at `dt`. The standard offset is taken to be the
+    /// smaller of the January 1 and July 1 offsets, which covers both hemispheres; zones without
+    /// DST (including `Utc` and fixed offsets) always report false.
+    fn is_dst<Tz: TimeZone>(&self, dt: &DateTime<Tz>) -> bool {
+        let tz = dt.timezone();
+        let offset_on = |month: u32| {
+            NaiveDate::from_ymd_opt(dt.year(), month, 1).map(|date| {
+                tz.offset_from_utc_datetime(&date.and_time(NaiveTime::MIN))
+                    .fix()
+                    .local_minus_utc()
+            })
         };
+        match (offset_on(1), offset_on(7)) {
+            (Some(january), Some(july)) => dt.offset().fix().local_minus_utc() > january.min(july),
+            _ => false,
+        }
+    }
 
-        let now = Utc::now().with_timezone(&tz);
-        Ok(self.format_time_info(now))
+    /// IANA name of the system timezone, from `TZ` or the platform's configuration
+    fn detect_local_timezone(&self) -> Option<Tz> {
+        let from_env = std::env::var("TZ")
+            .ok()
+            .and_then(|name| Tz::from_str(name.trim().trim_start_matches(':')).ok());
+        from_env.or_else(|| {
+            iana_time_zone::get_timezone()
+                .ok()
+                .and_then(|name| Tz::from_str(&name).ok())
+        })
+    }
+
+    /// Parse a standard 5-field cron expression (minute hour day-of-month month day-of-week)
+    fn parse_cron(&self, expression: &str) -> anyhow::Result<croner::Cron> {
+        limits::check_length("cron expression", expression)?;
+        croner::parser::CronParser::builder()
+            .seconds(croner::parser::Seconds::Disallowed)
+            .year(croner::parser::Year::Disallowed)
+            .build()
+            .parse(expression.trim())
+            .map_err(|e| anyhow::anyhow!("Invalid cron expression '{}': {}", expression, e))
+    }
+
+    /// Parse an offset like "+3 days", "-45 minutes" or "2 weeks 3 hours" into a duration.
+    /// A sign applies to its term and every following term until the next sign.
+    fn parse_relative_offset(&self, input: &str) -> anyhow::Result<chrono::Duration> {
+        limits::check_length("offset", input)?;
+        let invalid = || anyhow::anyhow!("Invalid offset: {} (expected e.g. \"+3 days\")", input);
+        let mut total = chrono::Duration::zero();
+        let mut negative = false;
+        // A sign must be followed by its own number and unit
+        let mut signed = false;
+        let mut pending: Option<i64> = None;
+        let mut terms = 0;
+
+        // Split signs, numbers and unit words into separate tokens ("+3days" -> "+", "3", "days")
+        let mut tokens: Vec<String> = Vec::new();
+        let mut current = String::new();
+        for c in input.chars() {
+            let boundary = c.is_whitespace()
+                || c == ','
+                || c == '+'
+                || c == '-'
+                || current
+                    .chars()
+                    .last()
+                    .is_some_and(|last| last.is_ascii_digit() != c.is_ascii_digit());
+            if boundary && !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+            if c == '+' || c == '-' {
+                tokens.push(c.to_string());
+            } else if !c.is_whitespace() && c != ',' {
+                current.push(c);
+            }
+        }
+        if !current.is_empty() {
+            tokens.push(current);
+        }
+
+        for token in &tokens {
+            match token.as_str() {
+                "+" | "-" => {
+                    if signed || pending.is_some() {
+                        return Err(invalid());
+                    }
+                    negative = token == "-";
+                    signed = true;
+                }
+                number if number.chars().all(|c| c.is_ascii_digit()) => {
+                    if pending.is_some() {
+                        return Err(invalid());
+                    }
+                    pending = Some(number.parse().map_err(|_| invalid())?);
+                }
+                unit => {
+                    let amount = pending.take().ok_or_else(invalid)?;
+                    let seconds_per_unit = match unit.to_lowercase().as_str() {
+                        "s" | "sec" | "secs" | "second" | "seconds" => 1,
+                        "m" | "min" | "mins" | "minute" | "minutes" => 60,
+                        "h" | "hr" | "hrs" | "hour" | "hours" => 3_600,
+                        "d" | "day" | "days" => 86_400,
+                        "w" | "wk" | "wks" | "week" | "weeks" => 604_800,
+                        _ => {
+                            return Err(anyhow::anyhow!(
+                                "Unrecognized unit '{}' in offset {} (use seconds, minutes, hours, days or weeks)",
+                                unit,
+                                input
+                            ))
+                        }
+                    };
+                    let seconds = amount
+                        .checked_mul(seconds_per_unit)
+                        .and_then(chrono::Duration::try_seconds)
+                        .ok_or_else(|| TimeDateError::out_of_range("Offset", input))?;
+                    total = if negative {
+                        total.checked_sub(&seconds)
+                    } else {
+                        total.checked_add(&seconds)
+                    }
+                    .ok_or_else(|| TimeDateError::out_of_range("Offset", input))?;
+                    signed = false;
+                    terms += 1;
+                }
+            }
+        }
+
+        if terms == 0 || pending.is_some() || signed {
+            return Err(invalid());
+        }
+        Ok(total)
+    }
+
+    /// Start of the first local day after `from` that is the nth `weekday` of its month
+    fn next_nth_weekday_after(
+        &self,
+        tz: &Tz,
+        weekday: Weekday,
+        n: i32,
+        from: &DateTime<Tz>,
+    ) -> anyhow::Result<DateTime<Tz>> {
+        if n == 0 || !(-5..=5).contains(&n) {
+            return Err(anyhow::anyhow!(
+                "n must be between 1 and 5, or -1 and -5 to count from the end of the month, got {}",
+                n
+            ));
+        }
+        let from_date = from.with_timezone(tz).date_naive();
+
+        // A fifth weekday can be absent for a few months in a row, so look over a year ahead
+        let mut month_start = from_date.with_day(1).unwrap_or(from_date);
+        for _ in 0..14 {
+            if let Some(date) = self.nth_weekday_of_month(month_start, weekday, n) {
+                if date > from_date {
+                    return self.start_of_local_day(tz, date);
+                }
+            }
+            month_start = month_start
+                .checked_add_months(chrono::Months::new(1))
+                .ok_or_else(|| TimeDateError::out_of_range("Date", month_start))?;
+        }
+        Err(anyhow::anyhow!(
+            "No occurrence of {} number {} found in the next year",
+            self.weekday_name(weekday),
+            n
+        ))
+    }
+
+    /// SplitMix64 mixing step: a fast, well-distributed 64-bit hash used as a seeded PRNG
+    fn splitmix64(&self, seed: u64) -> u64 {
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
     }
 
-    async fn list_timezones_internal(&self, filter: Option<String>) -> anyhow::Result<Vec<String>> {
-        let timezones: Vec<String> = TZ_VARIANTS
+    /// Parse an optional list of "YYYY-MM-DD" holiday dates
+    fn parse_holidays(&self, holidays: Option<Vec<String>>) -> anyhow::Result<Vec<NaiveDate>> {
+        let holidays = holidays.unwrap_or_default();
+        limits::check_list_len("holidays", holidays.len())?;
+        holidays
             .iter()
-            .map(|tz| tz.name().to_string())
-            .filter(|name| match &filter {
-                Some(f) => name.to_lowercase().contains(&f.to_lowercase()),
-                None => true,
+            .enumerate()
+            .map(|(index, date)| {
+                self.parse_date(date)
+                    .map_err(|e| anyhow::anyhow!("Holiday {}: {}", index, e))
             })
-            .take(50) // Limit results
-            .collect();
+            .collect()
+    }
 
-        Ok(timezones)
+    /// Whether a date is a Monday-Friday working day that is not a listed holiday
+    fn is_business_day(&self, date: NaiveDate, holidays: &[NaiveDate]) -> bool {
+        !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !holidays.contains(&date)
     }
 
-    async fn get_time_format_internal(&self) -> anyhow::Result<TimeFormatInfo> {
-        let now = Local::now();
+    /// Whole years elapsed from `start` to `today`, counting a year once its anniversary is reached
+    fn completed_years(&self, start: NaiveDate, today: NaiveDate) -> anyhow::Result<i32> {
+        let this_year = self.anniversary_in(start, today.year())?;
+        Ok(today.year() - start.year() - i32::from(this_year > today))
+    }
 
-        // Simple heuristic: check system locale or default to 24h
-        let is_12_hour = std::env::var("LC_TIME").unwrap_or_default().contains("US")
-            || std::env::var("LANG")
-                .unwrap_or_default()
-                .starts_with("en_US");
+    /// Age in fractional years: completed years plus the elapsed share of the current one
+    fn fractional_age(&self, birth: NaiveDate, today: NaiveDate) -> anyhow::Result<f64> {
+        let completed = self.completed_years(birth, today)?;
+        let last = self.anniversary_in(birth, birth.year() + completed)?;
+        let next = self.anniversary_in(birth, birth.year() + completed + 1)?;
+        let elapsed = today.signed_duration_since(last).num_days() as f64;
+        let length = next.signed_duration_since(last).num_days() as f64;
+        Ok(f64::from(completed) + elapsed / length)
+    }
 
-        Ok(TimeFormatInfo {
-            detected_format: if is_12_hour {
-                "12-hour".to_string()
+    /// Current instant from the server's clock
+    fn now(&self) -> DateTime<Utc> {
+        self.clock.now()
+    }
+
+    /// Describe a zone at a given instant
+    fn timezone_info_at<T: TimeZone>(&self, name: &str, now: &DateTime<T>) -> TimezoneInfo
+    where
+        T::Offset: std::fmt::Display,
+    {
+        TimezoneInfo {
+            name: name.to_string(),
+            current_time: now.format("%Y-%m-%d %H:%M:%S %Z").to_string(),
+            utc_offset: now.format("%:z").to_string(),
+            is_dst: self.is_dst(now),
+        }
+    }
+
+    /// Seconds of `[start, end)` that fall inside a daily local-clock window from `from_hour` to
+    /// `to_hour`, which wraps past midnight when `to_hour` is earlier
+    fn seconds_in_daily_window(
+        &self,
+        tz: &Tz,
+        start_dt: DateTime<Tz>,
+        end_dt: DateTime<Tz>,
+        from_hour: u32,
+        to_hour: u32,
+    ) -> anyhow::Result<i64> {
+        let first_day = start_dt.with_timezone(tz).date_naive();
+        let last_day = end_dt.with_timezone(tz).date_naive();
+        limits::check_span_days(
+            "window",
+            last_day.signed_duration_since(first_day).num_days(),
+        )?;
+
+        let from_time = NaiveTime::from_hms_opt(from_hour, 0, 0)
+            .ok_or_else(|| anyhow::anyhow!("Invalid window start hour"))?;
+        let to_time = NaiveTime::from_hms_opt(to_hour, 0, 0)
+            .ok_or_else(|| anyhow::anyhow!("Invalid window end hour"))?;
+
+        // A window that wraps midnight starts the evening before, so begin one day early
+        let wraps = to_hour < from_hour;
+        let mut seconds = 0;
+        let mut day = first_day.pred_opt().unwrap_or(first_day);
+        while day <= last_day {
+            let window_end_day = if wraps {
+                day.succ_opt().unwrap_or(day)
             } else {
-                "24-hour".to_string()
-            },
-            is_12_hour,
-            current_time_12h: now.format("%I:%M:%S %p").to_string(),
-            current_time_24h: now.format("%H:%M:%S").to_string(),
+                day
+            };
+            let window_start = self.resolve_local_time(tz, day.and_time(from_time))?;
+            let window_end = self.resolve_local_time(tz, window_end_day.and_time(to_time))?;
+
+            let overlap_start = window_start.max(start_dt);
+            let overlap_end = window_end.min(end_dt);
+            if overlap_end > overlap_start {
+                seconds += overlap_end
+                    .signed_duration_since(overlap_start)
+                    .num_seconds();
+            }
+            day = match day.succ_opt() {
+                Some(next) => next,
+                None => break,
+            };
+        }
+
+        Ok(seconds)
+    }
+
+    /// Calendar date of an ISO week-year, week number and weekday, explaining out-of-range weeks
+    fn iso_week_date(
+        &self,
+        iso_year: i32,
+        week: u32,
+        weekday: Weekday,
+    ) -> anyhow::Result<NaiveDate> {
+        limits::check_year(iso_year)?;
+        NaiveDate::from_isoywd_opt(iso_year, week, weekday).ok_or_else(|| {
+            // Week 53 only exists in years whose Dec 28 falls in week 53
+            let weeks = NaiveDate::from_ymd_opt(iso_year, 12, 28)
+                .map(|date| date.iso_week().week())
+                .unwrap_or(52);
+            anyhow::anyhow!(
+                "Invalid ISO week {} for {}: the year has {} weeks",
+                week,
+                iso_year,
+                weeks
+            )
         })
     }
-}
 
-impl TimeDateServer {
+    /// Decode "%XX" escapes in a resource URI segment. "+" stays a plus sign, as in URI paths.
+    fn percent_decode(&self, segment: &str) -> anyhow::Result<String> {
+        let invalid = || anyhow::anyhow!("Invalid percent-encoding in resource URI: {}", segment);
+        let bytes = segment.as_bytes();
+        let mut decoded = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == b'%' {
+                let hex = segment.get(i + 1..i + 3).ok_or_else(invalid)?;
+                decoded.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+                i += 3;
+            } else {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+        String::from_utf8(decoded).map_err(|_| invalid())
+    }
+
+    /// Call a tool by name through the generated dispatcher and join its text output. Boxed
+    /// because `batch` is itself one of the dispatched tools.
+    fn dispatch_tool(
+        &self,
+        request: CallToolRequestParam,
+    ) -> Pin<Box<dyn Future<Output = Result<String, pulseengine_mcp_protocol::Error>> + Send + '_>>
+    {
+        Box::pin(async move {
+            let result = self.call_tool_impl(request).await?;
+            Ok(result
+                .content
+                .into_iter()
+                .filter_map(|content| match content {
+                    Content::Text { text } => Some(text),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+                .join("\n"))
+        })
+    }
+
+    /// Instant of a local rounding boundary next to `dt`. A boundary read twice after clocks fall
+    /// back takes the reading on the same side of `dt`; one skipped by a gap moves to the gap's end.
+    fn boundary_instant(
+        &self,
+        tz: &Tz,
+        boundary: NaiveDateTime,
+        dt: &DateTime<Tz>,
+        before: bool,
+    ) -> anyhow::Result<DateTime<Tz>> {
+        match tz.from_local_datetime(&boundary) {
+            LocalResult::Single(instant) => Ok(instant),
+            LocalResult::Ambiguous(earliest, latest) => Ok(match before {
+                true if latest <= *dt => latest,
+                true => earliest,
+                false if earliest >= *dt => earliest,
+                false => latest,
+            }),
+            LocalResult::None => self.resolve_local_time(tz, boundary),
+        }
+    }
+
+    fn leap_year(&self, year: i32) -> bool {
+        year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+    }
+
+    /// Every IANA zone name with its lowercase form for case-insensitive filtering, sorted by
+    /// name. Built once and shared by all servers, since the list is fixed at compile time.
+    fn timezone_names(&self) -> &'static [(&'static str, String)] {
+        static NAMES: OnceLock<Vec<(&'static str, String)>> = OnceLock::new();
+        NAMES.get_or_init(|| {
+            let mut names: Vec<_> = TZ_VARIANTS
+                .iter()
+                .map(|tz| (tz.name(), tz.name().to_lowercase()))
+                .collect();
+            names.sort_unstable();
+            names
+        })
+    }
+
+    /// Julian calendar (year, month, day) of a Gregorian date, going through its Julian Day
+    /// Number with the Richards algorithm. Years are astronomical, so 1 BC is year 0.
+    fn julian_calendar_date(&self, date: NaiveDate) -> (i64, i64, i64) {
+        // num_days_from_ce is 1 on 0001-01-01, which is JDN 1721426
+        let jdn = i64::from(date.num_days_from_ce()) + 1_721_425;
+        let c = jdn + 32_082;
+        let d = (4 * c + 3).div_euclid(1461);
+        let e = c - (1461 * d).div_euclid(4);
+        let m = (5 * e + 2).div_euclid(153);
+        let day = e - (153 * m + 2).div_euclid(5) + 1;
+        let month = m + 3 - 12 * m.div_euclid(10);
+        let year = d - 4800 + m.div_euclid(10);
+        (year, month, day)
+    }
+
     fn format_time_info<Tz: TimeZone>(&self, dt: DateTime<Tz>) -> TimeInfo
     where
         Tz::Offset: std::fmt::Display,
@@ -272,10 +6024,28 @@ impl TimeDateServer {
         TimeInfo {
             timestamp: dt.to_rfc3339(),
             timezone: tz_name,
-            utc_offset: dt.format("%z").to_string(),
-            is_dst: false, // Simplified - would need more complex logic to detect DST
+            utc_offset: dt.format("%:z").to_string(),
+            is_dst: self.is_dst(&dt),
             format_12h: dt.format("%I:%M:%S %p").to_string(),
             format_24h: dt.format("%H:%M:%S").to_string(),
         }
     }
+
+    /// `format_time_info`, or just the timestamp in minimal mode so the other strings are never
+    /// built
+    fn time_output<Tz: TimeZone>(
+        &self,
+        dt: DateTime<Tz>,
+        verbosity: Option<Verbosity>,
+    ) -> TimeOutput
+    where
+        Tz::Offset: std::fmt::Display,
+    {
+        match verbosity.unwrap_or_default() {
+            Verbosity::Full => TimeOutput::Full(self.format_time_info(dt)),
+            Verbosity::Minimal => TimeOutput::Minimal(MinimalTimeInfo {
+                timestamp: dt.to_rfc3339(),
+            }),
+        }
+    }
 }
@@ -0,0 +1,135 @@
+//! Guardrails checked before tool arguments reach the parsers
+//!
+//! The shared timezone, date/time, offset, cron and format parsers call the string checks first,
+//! so an oversized or binary argument is refused before chrono, chrono-tz or the timezone
+//! suggestion search spend time on it. Counts, list lengths, spans and years go through the
+//! numeric checks. Every failure is a [`TimeDateError::InvalidInput`] naming the argument.
+
+use std::fmt::Display;
+
+use crate::TimeDateError;
+
+/// Longest string accepted for one argument, in bytes; real inputs stay well under 100
+pub(crate) const MAX_ARGUMENT_LEN: usize = 256;
+
+/// Upper bound on the number of entries series-producing tools will generate, and on the length
+/// of list arguments
+pub(crate) const MAX_SERIES_LEN: usize = 1000;
+
+/// Upper bound on how many days ahead scanning tools will look
+pub(crate) const MAX_HORIZON_DAYS: u32 = 3660;
+
+/// Upper bound on how many cron firings counting tools will step through
+pub(crate) const MAX_CRON_OCCURRENCES: usize = 100_000;
+
+/// Years accepted in dates and year arguments. Far inside chrono's ±262142, so stepping the
+/// longest horizon from any accepted date cannot overflow.
+pub(crate) const MIN_YEAR: i32 = -9999;
+pub(crate) const MAX_YEAR: i32 = 9999;
+
+/// Reject a string argument longer than `MAX_ARGUMENT_LEN`
+pub(crate) fn check_length(argument: &'static str, value: &str) -> Result<(), TimeDateError> {
+    if value.len() > MAX_ARGUMENT_LEN {
+        return Err(invalid(
+            argument,
+            format!(
+                "must be at most {} bytes, got {}",
+                MAX_ARGUMENT_LEN,
+                value.len()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// `check_length`, also rejecting control characters, for names and patterns handed to
+/// `Tz::from_str` or strftime
+pub(crate) fn check_name(argument: &'static str, value: &str) -> Result<(), TimeDateError> {
+    check_length(argument, value)?;
+    if value.chars().any(char::is_control) {
+        return Err(invalid(argument, "must not contain control characters"));
+    }
+    Ok(())
+}
+
+/// Reject a number above `max`
+pub(crate) fn check_at_most<T: PartialOrd + Display>(
+    argument: &'static str,
+    value: T,
+    max: T,
+) -> Result<(), TimeDateError> {
+    if value > max {
+        return Err(invalid(
+            argument,
+            format!("must be at most {max}, got {value}"),
+        ));
+    }
+    Ok(())
+}
+
+/// Reject a number outside `min..=max`; NaN is always outside
+pub(crate) fn check_between<T: PartialOrd + Display>(
+    argument: &'static str,
+    value: T,
+    min: T,
+    max: T,
+) -> Result<(), TimeDateError> {
+    if !(min <= value && value <= max) {
+        return Err(invalid(
+            argument,
+            format!("must be between {min} and {max}, got {value}"),
+        ));
+    }
+    Ok(())
+}
+
+/// Reject a list argument with more than `MAX_SERIES_LEN` entries
+pub(crate) fn check_list_len(argument: &'static str, len: usize) -> Result<(), TimeDateError> {
+    if len > MAX_SERIES_LEN {
+        return Err(invalid(
+            argument,
+            format!("must have at most {MAX_SERIES_LEN} entries, got {len}"),
+        ));
+    }
+    Ok(())
+}
+
+/// Reject a window spanning more than `MAX_HORIZON_DAYS` days
+pub(crate) fn check_span_days(argument: &'static str, days: i64) -> Result<(), TimeDateError> {
+    if days > i64::from(MAX_HORIZON_DAYS) {
+        return Err(invalid(
+            argument,
+            format!("must span at most {MAX_HORIZON_DAYS} days, got {days}"),
+        ));
+    }
+    Ok(())
+}
+
+/// Reject a window longer than `MAX_SERIES_LEN` hours, for tools listing one entry per hour
+pub(crate) fn check_span_hours(
+    argument: &'static str,
+    span: chrono::Duration,
+) -> Result<(), TimeDateError> {
+    if span > chrono::Duration::hours(MAX_SERIES_LEN as i64) {
+        return Err(invalid(
+            argument,
+            format!(
+                "must span at most {MAX_SERIES_LEN} hours, got {}",
+                span.num_hours()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Reject a year outside `MIN_YEAR..=MAX_YEAR`
+pub(crate) fn check_year(year: i32) -> Result<(), TimeDateError> {
+    check_between("year", year, MIN_YEAR, MAX_YEAR)
+}
+
+fn invalid(argument: &'static str, reason: impl Into<String>) -> TimeDateError {
+    TimeDateError::InvalidInput {
+        argument,
+        reason: reason.into(),
+    }
+}
@@ -0,0 +1,60 @@
+//! Embedded table of locales that conventionally use a 12-hour clock
+//!
+//! Everything not listed here is assumed to use a 24-hour clock, which is the common default
+//! worldwide and the safe choice for unknown locales.
+
+/// Language and territory pairs whose default time format is 12-hour
+const TWELVE_HOUR_LOCALES: &[(&str, &str)] = &[
+    ("en", "US"),
+    ("en", "CA"),
+    ("en", "AU"),
+    ("en", "NZ"),
+    ("en", "PH"),
+    ("en", "IN"),
+    ("en", "PK"),
+    ("en", "MY"),
+    ("en", "SG"),
+    ("en", "EG"),
+    ("en", "SA"),
+    ("es", "US"),
+    ("es", "MX"),
+    ("es", "CO"),
+    ("ar", "EG"),
+    ("ar", "SA"),
+    ("hi", "IN"),
+    ("bn", "BD"),
+    ("ur", "PK"),
+    ("fil", "PH"),
+    ("ko", "KR"),
+    ("zh", "TW"),
+];
+
+/// Languages whose default time format is 12-hour when no territory is given
+const TWELVE_HOUR_LANGUAGES: &[&str] = &["en", "ar", "hi", "bn", "ur", "fil", "ko"];
+
+/// Split a POSIX or BCP 47 locale such as "en_US.UTF-8", "en-AU" or "de_DE@euro" into
+/// a lowercase language and an optional uppercase territory
+fn parse_locale(locale: &str) -> Option<(String, Option<String>)> {
+    let base = locale.trim().split(['.', '@']).next().unwrap_or_default();
+    let mut parts = base.split(['_', '-']);
+    let language = parts.next().filter(|language| !language.is_empty())?;
+    if language.eq_ignore_ascii_case("C") || language.eq_ignore_ascii_case("POSIX") {
+        return None;
+    }
+    let territory = parts.next().filter(|territory| !territory.is_empty());
+    Some((
+        language.to_ascii_lowercase(),
+        territory.map(str::to_ascii_uppercase),
+    ))
+}
+
+/// Whether a locale conventionally uses a 12-hour clock
+pub(crate) fn uses_12_hour_clock(locale: &str) -> bool {
+    match parse_locale(locale) {
+        Some((language, Some(territory))) => TWELVE_HOUR_LOCALES
+            .iter()
+            .any(|(lang, terr)| *lang == language && *terr == territory),
+        Some((language, None)) => TWELVE_HOUR_LANGUAGES.contains(&language.as_str()),
+        None => false,
+    }
+}
@@ -9,7 +9,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     TimeDateServer::configure_stdio_logging();
 
     // Start the server using the macro-generated infrastructure
-    let mut server = TimeDateServer::with_defaults().serve_stdio().await?;
+    // Fails before serving when TIMEDATE_DEFAULT_TZ names an unknown zone
+    let mut server = TimeDateServer::from_env()?.serve_stdio().await?;
     server.run().await?;
 
     Ok(())
@@ -0,0 +1,134 @@
+//! Minimal SNTP client (RFC 4330) for reading time from a network source
+//!
+//! One request is sent and the offset is computed from the four packet timestamps, so the
+//! result does not depend on the local clock being right.
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use tokio::net::UdpSocket;
+
+use crate::Clock;
+
+/// Server queried when none is configured
+pub(crate) const DEFAULT_NTP_SERVER: &str = "pool.ntp.org";
+
+const NTP_PORT: u16 = 123;
+const TIMEOUT: Duration = Duration::from_secs(5);
+/// Seconds from the NTP epoch (1900-01-01) to the Unix epoch
+const NTP_TO_UNIX_SECONDS: i64 = 2_208_988_800;
+/// Leap indicator 0, version 4, mode 3 (client)
+const CLIENT_HEADER: u8 = 0b00_100_011;
+const MODE_SERVER: u8 = 4;
+
+pub(crate) struct NtpSample {
+    /// Network time at the moment the reply arrived
+    pub server_time: DateTime<Utc>,
+    /// How far the network time is ahead of the local clock
+    pub offset: chrono::Duration,
+    pub round_trip: chrono::Duration,
+    pub stratum: u8,
+}
+
+/// Query `server` ("host" or "host:port") and compare its time with `clock`
+pub(crate) async fn query(server: &str, clock: &dyn Clock) -> anyhow::Result<NtpSample> {
+    let addr = resolve(server).await?;
+    let bind_addr: SocketAddr = if addr.is_ipv4() {
+        ([0, 0, 0, 0], 0).into()
+    } else {
+        ([0u16; 8], 0).into()
+    };
+    let socket = UdpSocket::bind(bind_addr).await?;
+    socket.connect(addr).await?;
+
+    let mut request = [0u8; 48];
+    request[0] = CLIENT_HEADER;
+    let sent_at = clock.now();
+    // The server echoes this back as the originate timestamp, which ties the reply to the request
+    request[40..48].copy_from_slice(&to_ntp(sent_at).to_be_bytes());
+
+    let mut reply = [0u8; 48];
+    let exchange = async {
+        socket.send(&request).await?;
+        loop {
+            let len = socket.recv(&mut reply).await?;
+            if len >= 48 && reply[24..32] == request[40..48] {
+                return Ok::<_, std::io::Error>(());
+            }
+        }
+    };
+    tokio::time::timeout(TIMEOUT, exchange)
+        .await
+        .map_err(|_| {
+            anyhow::anyhow!(
+                "NTP server {} did not answer within {} seconds",
+                server,
+                TIMEOUT.as_secs()
+            )
+        })?
+        .map_err(|e| anyhow::anyhow!("NTP request to {} failed: {}", server, e))?;
+    let received_at = clock.now();
+
+    let stratum = reply[1];
+    if reply[0] & 0b111 != MODE_SERVER || stratum == 0 {
+        return Err(anyhow::anyhow!(
+            "NTP server {} refused the request (stratum {})",
+            server,
+            stratum
+        ));
+    }
+    let timestamp = |at: usize| {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&reply[at..at + 8]);
+        from_ntp(u64::from_be_bytes(bytes))
+    };
+    let server_received = timestamp(32)?;
+    let server_sent = timestamp(40)?;
+
+    let offset = ((server_received - sent_at) + (server_sent - received_at)) / 2;
+    let round_trip = (received_at - sent_at) - (server_sent - server_received);
+    Ok(NtpSample {
+        server_time: received_at + offset,
+        offset,
+        round_trip,
+        stratum,
+    })
+}
+
+async fn resolve(server: &str) -> anyhow::Result<SocketAddr> {
+    let server = server.trim();
+    // A single colon separates a port; IPv6 literals without a port contain several
+    let target = match server.rsplit_once(':') {
+        Some((host, port)) if !host.contains(':') => {
+            let port = port
+                .parse::<u16>()
+                .map_err(|_| anyhow::anyhow!("Invalid NTP server port: {}", server))?;
+            (host.trim_matches(['[', ']']).to_string(), port)
+        }
+        _ => (server.trim_matches(['[', ']']).to_string(), NTP_PORT),
+    };
+    tokio::net::lookup_host(target)
+        .await
+        .map_err(|e| anyhow::anyhow!("Could not resolve NTP server {}: {}", server, e))?
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("Could not resolve NTP server {}", server))
+}
+
+/// 64-bit NTP timestamp: seconds since 1900 in the high half, binary fraction in the low half
+fn to_ntp(instant: DateTime<Utc>) -> u64 {
+    let seconds = (instant.timestamp() + NTP_TO_UNIX_SECONDS) as u64;
+    let fraction = (u64::from(instant.timestamp_subsec_nanos()) << 32) / 1_000_000_000;
+    (seconds << 32) | fraction
+}
+
+fn from_ntp(timestamp: u64) -> anyhow::Result<DateTime<Utc>> {
+    let mut seconds = (timestamp >> 32) as i64;
+    // Era 1 starts in February 2036; small values are read as belonging to it
+    if seconds < 1 << 31 {
+        seconds += 1 << 32;
+    }
+    let nanos = ((timestamp & 0xFFFF_FFFF) * 1_000_000_000) >> 32;
+    DateTime::from_timestamp(seconds - NTP_TO_UNIX_SECONDS, nanos as u32)
+        .ok_or_else(|| anyhow::anyhow!("NTP timestamp out of range"))
+}
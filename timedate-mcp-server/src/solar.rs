@@ -0,0 +1,166 @@
+//! Solar position calculations using the NOAA general solar position approximation
+//!
+//! Accuracy is roughly a minute for latitudes between the polar circles, which is more than
+//! enough for sunrise/sunset style answers. All results are minutes after UTC midnight of the
+//! given calendar date and may fall slightly outside `0..1440` for locations far from Greenwich.
+
+use chrono::{Datelike, NaiveDate};
+
+/// Solar zenith angle for sunrise/sunset, accounting for refraction and the solar disc radius
+const SUNRISE_ZENITH_DEGREES: f64 = 90.833;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum DaylightStatus {
+    Normal,
+    PolarDay,
+    PolarNight,
+}
+
+impl DaylightStatus {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            DaylightStatus::Normal => "normal",
+            DaylightStatus::PolarDay => "polar_day",
+            DaylightStatus::PolarNight => "polar_night",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SolarDay {
+    pub status: DaylightStatus,
+    pub sunrise_minutes: Option<f64>,
+    pub sunset_minutes: Option<f64>,
+    pub solar_noon_minutes: f64,
+}
+
+impl SolarDay {
+    /// Length of daylight in minutes (a full day for polar day, zero for polar night)
+    pub(crate) fn day_length_minutes(&self) -> f64 {
+        match (self.status, self.sunrise_minutes, self.sunset_minutes) {
+            (DaylightStatus::PolarDay, _, _) => 1440.0,
+            (DaylightStatus::Normal, Some(rise), Some(set)) => set - rise,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Compute sunrise, sunset and solar noon for a date at the given coordinates.
+/// Longitude is positive east of Greenwich.
+pub(crate) fn solar_day(date: NaiveDate, latitude: f64, longitude: f64) -> SolarDay {
+    let days_in_year = if date.leap_year() { 366.0 } else { 365.0 };
+    let gamma = 2.0 * std::f64::consts::PI / days_in_year * f64::from(date.ordinal0());
+
+    let eq_time = 229.18
+        * (0.000075 + 0.001868 * gamma.cos()
+            - 0.032077 * gamma.sin()
+            - 0.014615 * (2.0 * gamma).cos()
+            - 0.040849 * (2.0 * gamma).sin());
+    let declination = 0.006918 - 0.399912 * gamma.cos() + 0.070257 * gamma.sin()
+        - 0.006758 * (2.0 * gamma).cos()
+        + 0.000907 * (2.0 * gamma).sin()
+        - 0.002697 * (3.0 * gamma).cos()
+        + 0.00148 * (3.0 * gamma).sin();
+
+    let solar_noon_minutes = 720.0 - 4.0 * longitude - eq_time;
+
+    let lat = latitude.to_radians();
+    let cos_hour_angle = SUNRISE_ZENITH_DEGREES.to_radians().cos()
+        / (lat.cos() * declination.cos())
+        - lat.tan() * declination.tan();
+
+    if cos_hour_angle > 1.0 {
+        return SolarDay {
+            status: DaylightStatus::PolarNight,
+            sunrise_minutes: None,
+            sunset_minutes: None,
+            solar_noon_minutes,
+        };
+    }
+    if cos_hour_angle < -1.0 {
+        return SolarDay {
+            status: DaylightStatus::PolarDay,
+            sunrise_minutes: None,
+            sunset_minutes: None,
+            solar_noon_minutes,
+        };
+    }
+
+    let hour_angle = cos_hour_angle.acos().to_degrees();
+    SolarDay {
+        status: DaylightStatus::Normal,
+        sunrise_minutes: Some(solar_noon_minutes - 4.0 * hour_angle),
+        sunset_minutes: Some(solar_noon_minutes + 4.0 * hour_angle),
+        solar_noon_minutes,
+    }
+}
+
+/// Periodic terms (A, B, C) from Meeus, "Astronomical Algorithms", table 27.C
+const SEASON_PERIODIC_TERMS: [(f64, f64, f64); 24] = [
+    (485.0, 324.96, 1934.136),
+    (203.0, 337.23, 32964.467),
+    (199.0, 342.08, 20.186),
+    (182.0, 27.85, 445267.112),
+    (156.0, 73.14, 45036.886),
+    (136.0, 171.52, 22518.443),
+    (77.0, 222.54, 65928.934),
+    (74.0, 296.72, 3034.906),
+    (70.0, 243.58, 9037.513),
+    (58.0, 119.81, 33718.147),
+    (52.0, 297.17, 150.678),
+    (50.0, 21.02, 2281.226),
+    (45.0, 247.54, 29929.562),
+    (44.0, 325.15, 31555.956),
+    (29.0, 60.93, 4443.417),
+    (18.0, 155.12, 67555.328),
+    (17.0, 288.79, 4562.452),
+    (16.0, 198.04, 62894.029),
+    (14.0, 199.76, 31436.921),
+    (12.0, 95.39, 14577.848),
+    (12.0, 287.11, 31931.756),
+    (12.0, 320.81, 34777.259),
+    (9.0, 227.73, 1222.114),
+    (8.0, 15.45, 16859.074),
+];
+
+/// Instants of the March equinox, June solstice, September equinox and December solstice
+/// as Julian Days in UT, following Meeus chapter 27 (valid for years 1000-3000).
+pub(crate) fn equinoxes_and_solstices(year: i32) -> [f64; 4] {
+    let y = (f64::from(year) - 2000.0) / 1000.0;
+    let mean = [
+        2451623.80984 + 365242.37404 * y + 0.05169 * y.powi(2)
+            - 0.00411 * y.powi(3)
+            - 0.00057 * y.powi(4),
+        2451716.56767 + 365241.62603 * y + 0.00325 * y.powi(2) + 0.00888 * y.powi(3)
+            - 0.00030 * y.powi(4),
+        2451810.21715 + 365242.01767 * y - 0.11575 * y.powi(2)
+            + 0.00337 * y.powi(3)
+            + 0.00078 * y.powi(4),
+        2451900.05952 + 365242.74049 * y - 0.06223 * y.powi(2) - 0.00823 * y.powi(3)
+            + 0.00032 * y.powi(4),
+    ];
+    let delta_t_days = delta_t_seconds(year) / 86400.0;
+
+    mean.map(|jde0| {
+        let t = (jde0 - 2451545.0) / 36525.0;
+        let w = (35999.373 * t - 2.47_f64).to_radians();
+        let delta_lambda = 1.0 + 0.0334 * w.cos() + 0.0007 * (2.0 * w).cos();
+        let s: f64 = SEASON_PERIODIC_TERMS
+            .iter()
+            .map(|(a, b, c)| a * (b + c * t).to_radians().cos())
+            .sum();
+        jde0 + 0.00001 * s / delta_lambda - delta_t_days
+    })
+}
+
+/// Approximate difference between Terrestrial Time and UT (NASA polynomial expressions)
+fn delta_t_seconds(year: i32) -> f64 {
+    let y = f64::from(year);
+    if (2005..=2050).contains(&year) {
+        let t = y - 2000.0;
+        62.92 + 0.32217 * t + 0.005589 * t * t
+    } else {
+        let u = (y - 1820.0) / 100.0;
+        -20.0 + 32.0 * u * u
+    }
+}
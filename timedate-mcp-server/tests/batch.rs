@@ -0,0 +1,64 @@
+//! The batch tool takes its operations directly and runs them through the normal dispatcher
+
+mod common;
+
+use pulseengine_mcp_protocol::{CallToolRequestParam, Content, PaginatedRequestParam};
+use pulseengine_mcp_server::McpBackend;
+
+#[tokio::test]
+async fn batch_schema_takes_operations_directly() {
+    let server = common::server_at("2024-06-01T12:00:00Z");
+    let tools = server
+        .list_tools(PaginatedRequestParam { cursor: None })
+        .await
+        .unwrap()
+        .tools;
+    let batch = tools.iter().find(|tool| tool.name == "batch").unwrap();
+    let schema = serde_json::to_value(&batch.input_schema).unwrap();
+
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["properties"]["operations"]["type"], "array");
+    assert_eq!(schema["required"], serde_json::json!(["operations"]));
+}
+
+#[tokio::test]
+async fn batch_runs_every_operation_in_order() {
+    let server = common::server_at("2024-06-01T12:00:00Z");
+    let operations = serde_json::json!([
+        {"operation": "is_leap_year", "arguments": {"year": 2024}},
+        {"operation": "no_such_tool"},
+        {"operation": "is_leap_year", "arguments": {"year": 1900}},
+    ]);
+
+    let results = server
+        .batch(serde_json::from_value(operations.clone()).unwrap(), None)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].result.as_deref(), Some("true"));
+    assert!(results[1].error.is_some());
+    assert_eq!(results[2].result.as_deref(), Some("false"));
+
+    let stopped = server
+        .batch(
+            serde_json::from_value(operations.clone()).unwrap(),
+            Some(true),
+        )
+        .await
+        .unwrap();
+    assert_eq!(stopped.len(), 2);
+
+    // Clients send the list under "operations", with no wrapping object
+    let response = server
+        .call_tool(CallToolRequestParam {
+            name: "batch".to_string(),
+            arguments: Some(serde_json::json!({"operations": operations})),
+        })
+        .await
+        .unwrap();
+    assert_eq!(response.is_error, Some(false));
+    match &response.content[0] {
+        Content::Text { text, .. } => assert!(text.contains("no_such_tool")),
+        other => panic!("unexpected content {other:?}"),
+    }
+}
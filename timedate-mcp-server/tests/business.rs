@@ -0,0 +1,107 @@
+//! Business hours, working days and deadlines
+
+mod common;
+
+use common::{full, server_at};
+use timedate_mcp_server::RecurrenceSpec;
+
+#[tokio::test]
+async fn is_business_hours_checks_the_hour_and_the_weekday() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let check = |at: &str| {
+        server.is_business_hours(
+            "America/New_York".to_string(),
+            9,
+            17,
+            None,
+            Some(at.to_string()),
+        )
+    };
+
+    // Wednesday 10:00 and 18:00 in New York, then Saturday 10:00
+    assert!(check("2024-06-05T14:00:00Z").await.unwrap());
+    assert!(!check("2024-06-05T22:00:00Z").await.unwrap());
+    assert!(!check("2024-06-08T14:00:00Z").await.unwrap());
+}
+
+#[tokio::test]
+async fn next_open_rolls_over_weekends_and_open_days() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let next_open = |from: &str| {
+        server.next_open(
+            "America/New_York".to_string(),
+            9,
+            17,
+            None,
+            Some(from.to_string()),
+            None,
+        )
+    };
+
+    // Saturday opens on Monday; mid-Wednesday opens again on Thursday
+    let from_weekend = full(next_open("2024-06-08T14:00:00Z").await.unwrap());
+    assert_eq!(from_weekend.timestamp, "2024-06-10T09:00:00-04:00");
+    let from_open_day = full(next_open("2024-06-05T16:00:00Z").await.unwrap());
+    assert_eq!(from_open_day.timestamp, "2024-06-06T09:00:00-04:00");
+}
+
+#[tokio::test]
+async fn recurrences_collide_finds_the_first_shared_instant() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let weekly = |weekday: &str, time: &str, timezone: &str| RecurrenceSpec {
+        weekday: weekday.to_string(),
+        time: time.to_string(),
+        timezone: timezone.to_string(),
+        duration_minutes: 60,
+        starts_on: Some("2024-06-03".to_string()),
+    };
+
+    // 14:00 in London is 09:00 in New York, so the hours overlap from 09:30 New York time
+    let collision = server
+        .recurrences_collide(
+            weekly("monday", "09:30", "America/New_York"),
+            weekly("monday", "14:00", "Europe/London"),
+            28,
+        )
+        .await
+        .unwrap()
+        .expect("the meetings overlap");
+    assert_eq!(
+        chrono::DateTime::parse_from_rfc3339(&collision).unwrap(),
+        chrono::DateTime::parse_from_rfc3339("2024-06-03T13:30:00Z").unwrap()
+    );
+
+    let apart = server
+        .recurrences_collide(
+            weekly("monday", "09:30", "America/New_York"),
+            weekly("tuesday", "09:30", "America/New_York"),
+            28,
+        )
+        .await
+        .unwrap();
+    assert_eq!(apart, None);
+}
+
+#[tokio::test]
+async fn business_deadline_skips_weekends_and_closed_hours() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let deadline = |business_days: u32, extra_hours: u32, from: &str| {
+        server.business_deadline(
+            business_days,
+            extra_hours,
+            "America/New_York".to_string(),
+            9,
+            17,
+            Some(from.to_string()),
+            None,
+        )
+    };
+
+    // One business day from Friday 10:00 lands on Monday
+    let over_weekend = full(deadline(1, 0, "2024-06-07T14:00:00Z").await.unwrap());
+    assert_eq!(over_weekend.timestamp, "2024-06-10T10:00:00-04:00");
+
+    // Four working hours from Wednesday 15:00: two remain when the day closes at 17:00
+    let over_closing = full(deadline(0, 4, "2024-06-05T19:00:00Z").await.unwrap());
+    assert_eq!(over_closing.timestamp, "2024-06-06T11:00:00-04:00");
+}
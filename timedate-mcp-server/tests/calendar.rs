@@ -0,0 +1,362 @@
+//! Calendar arithmetic, weeks, months and alternate calendars
+
+mod common;
+
+use common::{error_code, full, server_at};
+use timedate_mcp_server::Calendar;
+
+#[tokio::test]
+async fn day_count_30_360_applies_the_end_of_month_rules() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let count =
+        |start: &str, end: &str| server.day_count_30_360(start.to_string(), end.to_string());
+
+    assert_eq!(count("2024-01-15", "2024-07-15").await.unwrap(), 180);
+    // A 31st start is treated as the 30th, and a 31st end then follows it
+    assert_eq!(count("2024-01-31", "2024-03-31").await.unwrap(), 60);
+    // A 31st end after a start before the 30th is kept
+    assert_eq!(count("2024-01-15", "2024-03-31").await.unwrap(), 76);
+    // The last day of February counts as the 30th
+    assert_eq!(count("2024-02-29", "2024-03-31").await.unwrap(), 30);
+    assert_eq!(count("2023-02-28", "2024-02-29").await.unwrap(), 360);
+}
+
+#[tokio::test]
+async fn month_progress_runs_from_zero_to_one_over_the_month() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let progress = |at: &str| server.month_progress(at.to_string(), None);
+
+    for (first, last) in [
+        ("2023-02-01 00:00", "2023-02-28 23:59"),
+        ("2024-01-01 00:00", "2024-01-31 23:59"),
+    ] {
+        assert_eq!(progress(first).await.unwrap(), 0.0);
+        let end = progress(last).await.unwrap();
+        assert!(end > 0.999 && end < 1.0, "{last}: {end}");
+    }
+    assert_eq!(progress("2023-02-15 00:00").await.unwrap(), 0.5);
+}
+
+#[tokio::test]
+async fn holiday_week_flags_monday_holidays_as_long_weekends() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let memorial_day = server.holiday_week("2024-05-27".to_string()).await.unwrap();
+    assert_eq!(memorial_day.weekday, "Monday");
+    assert_eq!((memorial_day.iso_year, memorial_day.iso_week), (2024, 22));
+    assert_eq!(memorial_day.week_start, "2024-05-27");
+    assert_eq!(memorial_day.week_end, "2024-06-02");
+    assert!(memorial_day.long_weekend);
+
+    let christmas = server.holiday_week("2024-12-25".to_string()).await.unwrap();
+    assert_eq!(christmas.weekday, "Wednesday");
+    assert!(!christmas.long_weekend);
+}
+
+#[tokio::test]
+async fn week_anchors_follow_the_configured_week_start() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let anchors = |week_start: Option<&str>| {
+        server.week_anchors(
+            "2024-06-05 12:00".to_string(),
+            week_start.map(str::to_string),
+            None,
+        )
+    };
+
+    // Wednesday 5 June 2024
+    let monday = anchors(None).await.unwrap();
+    assert_eq!(monday.first_day, "2024-06-03");
+    assert_eq!(monday.last_day, "2024-06-09");
+    let sunday = anchors(Some("sunday")).await.unwrap();
+    assert_eq!(sunday.first_day, "2024-06-02");
+    assert_eq!(sunday.last_day, "2024-06-08");
+}
+
+#[tokio::test]
+async fn monthly_on_day_clamps_the_31st_in_short_months() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    for (start, expected) in [
+        (
+            "2024-01-01 09:00",
+            ["2024-01-31", "2024-02-29", "2024-03-31", "2024-04-30"],
+        ),
+        (
+            "2023-01-01 09:00",
+            ["2023-01-31", "2023-02-28", "2023-03-31", "2023-04-30"],
+        ),
+    ] {
+        let occurrences = server
+            .monthly_on_day(31, start.to_string(), 4, None)
+            .await
+            .unwrap();
+        let dates: Vec<&str> = occurrences.iter().map(|date| &date[..10]).collect();
+        assert_eq!(dates, expected);
+        assert!(occurrences[0].contains("T09:00:00"));
+    }
+}
+
+#[tokio::test]
+async fn decimal_year_uses_the_real_year_length() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let decimal = |at: &str| server.decimal_year(at.to_string());
+
+    assert_eq!(decimal("2024-01-01T00:00:00Z").await.unwrap(), 2024.0);
+    // 183 of the 366 days of 2024 have passed by 2 July
+    assert_eq!(decimal("2024-07-02T00:00:00Z").await.unwrap(), 2024.5);
+    let mid_2023 = decimal("2023-07-02T12:00:00Z").await.unwrap();
+    assert!((mid_2023 - 2023.5).abs() < 1e-9, "{mid_2023}");
+}
+
+#[tokio::test]
+async fn weekends_in_month_counts_days_and_complete_weekends() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    // September 2024 starts on a Sunday whose Saturday is in August
+    let september = server.weekends_in_month(2024, 9).await.unwrap();
+    assert_eq!((september.weekend_days, september.full_weekends), (9, 4));
+
+    // May 2024 starts on a Wednesday
+    let may = server.weekends_in_month(2024, 5).await.unwrap();
+    assert_eq!((may.weekend_days, may.full_weekends), (8, 4));
+}
+
+#[tokio::test]
+async fn next_nth_weekday_finds_the_next_first_monday() {
+    let server = server_at("2024-06-15T12:00:00Z");
+
+    let first_monday = server
+        .next_nth_weekday("monday".to_string(), 1, None, None, None)
+        .await
+        .unwrap();
+    assert_eq!(full(first_monday).timestamp, "2024-07-01T00:00:00+00:00");
+}
+
+#[tokio::test]
+async fn half_of_year_splits_at_the_start_of_july() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let first = server
+        .half_of_year("2024-06-30 23:59".to_string(), None)
+        .await
+        .unwrap();
+    assert_eq!(first.half, 1);
+    assert_eq!(
+        (first.first_day.as_str(), first.last_day.as_str()),
+        ("2024-01-01", "2024-06-30")
+    );
+
+    let second = server
+        .half_of_year("2024-07-01 00:00".to_string(), None)
+        .await
+        .unwrap();
+    assert_eq!(second.half, 2);
+    assert_eq!(
+        (second.first_day.as_str(), second.last_day.as_str()),
+        ("2024-07-01", "2024-12-31")
+    );
+}
+
+#[tokio::test]
+async fn years_of_service_observes_feb_29_anniversaries_on_feb_28() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let normal = server
+        .years_of_service("2020-03-15".to_string(), None)
+        .await
+        .unwrap();
+    assert_eq!(normal.completed_years, 4);
+    assert_eq!(normal.next_anniversary, "2025-03-15");
+    assert_eq!(normal.days_until_next, 287);
+
+    let leap_day = server
+        .years_of_service("2020-02-29".to_string(), Some("2023-01-10".to_string()))
+        .await
+        .unwrap();
+    assert_eq!(leap_day.completed_years, 2);
+    assert_eq!(leap_day.next_anniversary, "2023-02-28");
+    assert_eq!(leap_day.days_until_next, 49);
+}
+
+#[tokio::test]
+async fn next_matching_combines_constraints_and_rejects_impossible_ones() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let monday_first = server
+        .next_matching(
+            Some("monday".to_string()),
+            Some(1),
+            None,
+            Some("09:00".to_string()),
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(full(monday_first).timestamp, "2024-07-01T09:00:00+00:00");
+
+    let february_30 = server
+        .next_matching(None, Some(30), Some(2), None, None, None, None)
+        .await;
+    assert!(february_30.is_err());
+}
+
+#[tokio::test]
+async fn from_iso_week_converts_week_dates_and_rejects_missing_weeks() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    // ISO week 1 of 2024 starts on Monday 1 January
+    let first = server
+        .from_iso_week(2024, 1, "monday".to_string())
+        .await
+        .unwrap();
+    assert_eq!(first, "2024-01-01");
+    let missing = server.from_iso_week(2024, 54, "monday".to_string()).await;
+    assert!(missing.is_err());
+}
+
+#[tokio::test]
+async fn get_period_bounds_spans_whole_months_and_weeks() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let bounds = |date_time: &str, period: &str| {
+        server.get_period_bounds(date_time.to_string(), period.to_string(), None, None)
+    };
+
+    for (date_time, start, end) in [
+        ("2023-02-14 08:00", "2023-02-01", "2023-03-01"),
+        ("2024-04-30 23:59", "2024-04-01", "2024-05-01"),
+        ("2024-01-31 00:00", "2024-01-01", "2024-02-01"),
+    ] {
+        let month = bounds(date_time, "month").await.unwrap();
+        assert_eq!(month.start, format!("{start}T00:00:00+00:00"));
+        assert_eq!(month.end, format!("{end}T00:00:00+00:00"));
+    }
+
+    // Friday 31 May 2024 is in a week that ends in June
+    let week = bounds("2024-05-31 12:00", "week").await.unwrap();
+    assert_eq!(week.start, "2024-05-27T00:00:00+00:00");
+    assert_eq!(week.end, "2024-06-03T00:00:00+00:00");
+}
+
+#[tokio::test]
+async fn iso_week_dates_round_trip_across_years() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    // 2020 has 53 ISO weeks, and its last week runs into 2021
+    let last_week = server
+        .parse_iso_week("2020-W53-5".to_string(), None, None)
+        .await
+        .unwrap();
+    assert_eq!(full(last_week).timestamp, "2021-01-01T00:00:00+00:00");
+    assert!(server
+        .parse_iso_week("2021-W53-1".to_string(), None, None)
+        .await
+        .is_err());
+
+    // New Year's Day 2022 is a Saturday in the last ISO week of 2021
+    let to_week = |date_time: &str| server.to_iso_week(date_time.to_string(), None);
+    assert_eq!(to_week("2021-01-01").await.unwrap(), "2020-W53-5");
+    assert_eq!(to_week("2022-01-01").await.unwrap(), "2021-W52-6");
+}
+
+#[tokio::test]
+async fn julian_dates_match_known_anchors_and_round_trip_to_the_second() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let j2000 = server
+        .to_julian_day("2000-01-01T12:00:00Z".to_string(), None)
+        .await
+        .unwrap();
+    assert_eq!(j2000.julian_day_number, 2_451_545);
+    assert_eq!(j2000.julian_date, 2_451_545.0);
+    assert_eq!(j2000.modified_julian_date, 51_544.5);
+    let mjd_epoch = server
+        .to_julian_day("1858-11-17T00:00:00Z".to_string(), None)
+        .await
+        .unwrap();
+    assert_eq!(mjd_epoch.modified_julian_date, 0.0);
+
+    for instant in [
+        "2000-01-01T12:00:00+00:00",
+        "1970-01-01T00:00:00+00:00",
+        "2024-06-01T23:59:59+00:00",
+        "1582-10-15T07:31:13+00:00",
+        "2099-12-31T00:00:01+00:00",
+    ] {
+        let julian = server
+            .to_julian_day(instant.to_string(), None)
+            .await
+            .unwrap();
+        let back = server
+            .from_julian_day(julian.julian_date, None, None)
+            .await
+            .unwrap();
+        assert_eq!(full(back).timestamp, instant);
+    }
+}
+
+#[tokio::test]
+async fn leap_years_follow_the_century_rule() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    for (year, leap) in [(2000, true), (1900, false), (2024, true), (2023, false)] {
+        assert_eq!(server.is_leap_year(year).await.unwrap(), leap, "{year}");
+        let facts = server.calendar_facts(year).await.unwrap();
+        assert_eq!(facts.is_leap_year, leap);
+        assert_eq!(facts.days_in_year, if leap { 366 } else { 365 });
+        assert_eq!(facts.days_in_month[1], if leap { 29 } else { 28 });
+        assert_eq!(facts.days_in_month.iter().sum::<u32>(), facts.days_in_year);
+    }
+}
+
+#[tokio::test]
+async fn leap_second_inputs_are_rejected() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let leap_second = server
+        .get_time_at("2016-12-31T23:59:60Z".to_string(), None, None)
+        .await;
+    assert_eq!(error_code(leap_second), "invalid_date_format");
+}
+
+/// Julian Day Number of a proleptic Julian calendar date, for years after 4800 BC
+fn jdn_from_julian_calendar(year: i64, month: i64, day: i64) -> i64 {
+    367 * year - (7 * (year + 5001 + (month - 9) / 7)) / 4 + (275 * month) / 9 + day + 1_729_777
+}
+
+#[tokio::test]
+async fn julian_calendar_dates_match_known_dates_and_round_trip_through_the_day_number() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let julian =
+        |date: &str| server.to_alternate_calendar(date.to_string(), Calendar::Julian, None);
+
+    for (gregorian, expected) in [
+        // The first day of the Gregorian calendar followed Julian 4 October 1582
+        ("1582-10-15", "1582-10-05"),
+        // 1900 was a leap year only in the Julian calendar
+        ("1900-03-13", "1900-02-29"),
+        ("1900-03-14", "1900-03-01"),
+        ("2024-06-01", "2024-05-19"),
+        ("2100-03-14", "2100-02-29"),
+    ] {
+        let converted = julian(gregorian).await.unwrap();
+        assert_eq!(converted.value, expected, "{gregorian}");
+        assert_eq!(converted.gregorian_date, gregorian);
+
+        let parts: Vec<i64> = expected
+            .split('-')
+            .map(|part| part.parse().unwrap())
+            .collect();
+        let day_number = server
+            .to_julian_day(format!("{gregorian}T12:00:00Z"), None)
+            .await
+            .unwrap()
+            .julian_day_number;
+        assert_eq!(
+            jdn_from_julian_calendar(parts[0], parts[1], parts[2]),
+            day_number
+        );
+    }
+}
@@ -0,0 +1,25 @@
+//! Shared setup for the tool tests: a server whose clock is pinned to a fixed instant
+#![allow(dead_code)]
+
+use timedate_mcp_server::{FixedClock, TimeDateError, TimeDateServer, TimeInfo, TimeOutput};
+
+/// A server that reports `now` as the given RFC3339 instant and defaults to UTC
+pub fn server_at(now: &str) -> TimeDateServer {
+    let now = chrono::DateTime::parse_from_rfc3339(now).expect("valid RFC3339 instant");
+    TimeDateServer::with_clock(FixedClock(now.to_utc()))
+}
+
+/// The full form of a tool's time output
+pub fn full(output: TimeOutput) -> TimeInfo {
+    match output {
+        TimeOutput::Full(info) => info,
+        TimeOutput::Minimal(info) => panic!("expected the full time form, got {info:?}"),
+    }
+}
+
+/// The `TimeDateError` code of a failed call, or "untyped" for a plain error message
+pub fn error_code<T: std::fmt::Debug>(result: anyhow::Result<T>) -> &'static str {
+    let err = result.expect_err("call should fail");
+    err.downcast_ref::<TimeDateError>()
+        .map_or("untyped", TimeDateError::code)
+}
@@ -0,0 +1,321 @@
+//! Offsets, spans and elapsed-time tools
+
+mod common;
+
+use common::{error_code, full, server_at};
+use timedate_mcp_server::{Interval, TimeUnit};
+
+#[tokio::test]
+async fn natural_language_offsets_reject_dangling_signs() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let offset_from_noon = |offset: &str| {
+        server.calculate_time_offset(
+            "2024-06-01T12:00:00Z".to_string(),
+            None,
+            Some(offset.to_string()),
+            None,
+            None,
+        )
+    };
+
+    let shifted = full(offset_from_noon("+3 days -2h").await.unwrap());
+    assert_eq!(shifted.timestamp, "2024-06-04T10:00:00+00:00");
+
+    for invalid in ["+3 days -", "3 days + - 2h", "- 3 + days", "+"] {
+        let err = offset_from_noon(invalid).await.expect_err(invalid);
+        assert!(
+            err.to_string().starts_with("Invalid offset"),
+            "{invalid}: {err}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn recent_boundaries_lists_the_last_hour_marks() {
+    let server = server_at("2024-06-01T12:34:56Z");
+
+    let hours = server
+        .recent_boundaries(TimeUnit::Hour, 3, None, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        hours,
+        [
+            "2024-06-01T12:00:00+00:00",
+            "2024-06-01T11:00:00+00:00",
+            "2024-06-01T10:00:00+00:00",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn version_age_counts_from_the_release_date() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let age = server
+        .version_age(
+            "2024-01-01".to_string(),
+            Some("2024-03-01T00:00:00Z".to_string()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(age.version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(age.age_days, 60);
+    assert_eq!(age.age_seconds, 60 * 86_400);
+}
+
+#[tokio::test]
+async fn resolve_relative_moves_forward_and_back_from_the_base() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let resolve = |phrase: &str| {
+        server.resolve_relative(
+            phrase.to_string(),
+            "2024-06-01T12:00:00Z".to_string(),
+            None,
+            None,
+        )
+    };
+
+    let ahead = full(resolve("in 3 days").await.unwrap());
+    assert_eq!(ahead.timestamp, "2024-06-04T12:00:00+00:00");
+    let ago = full(resolve("2 hours ago").await.unwrap());
+    assert_eq!(ago.timestamp, "2024-06-01T10:00:00+00:00");
+}
+
+/// Length of an interval in milliseconds
+fn interval_millis(interval: &Interval) -> i64 {
+    let start = chrono::DateTime::parse_from_rfc3339(&interval.start).unwrap();
+    let end = chrono::DateTime::parse_from_rfc3339(&interval.end).unwrap();
+    (end - start).num_milliseconds()
+}
+
+#[tokio::test]
+async fn split_window_gives_the_remainder_to_the_last_slot() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let even = server
+        .split_window(
+            "2024-06-01T00:00:00Z".to_string(),
+            "2024-06-01T03:00:00Z".to_string(),
+            3,
+        )
+        .await
+        .unwrap();
+    let lengths: Vec<i64> = even.iter().map(interval_millis).collect();
+    assert_eq!(lengths, [3_600_000; 3]);
+    assert_eq!(even[0].end, even[1].start);
+
+    let uneven = server
+        .split_window(
+            "2024-06-01T00:00:00Z".to_string(),
+            "2024-06-01T00:00:10Z".to_string(),
+            3,
+        )
+        .await
+        .unwrap();
+    let lengths: Vec<i64> = uneven.iter().map(interval_millis).collect();
+    assert_eq!(lengths, [3_333, 3_333, 3_334]);
+}
+
+#[tokio::test]
+async fn elapsed_ticks_counts_fractional_ticks() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let ticks = server
+        .elapsed_ticks(
+            "2024-06-01T00:00:00Z".to_string(),
+            "2024-06-01T01:00:00Z".to_string(),
+            86.4,
+        )
+        .await
+        .unwrap();
+    assert!((ticks - 41.666_666).abs() < 1e-3, "{ticks}");
+}
+
+#[tokio::test]
+async fn time_until_day_fraction_rolls_past_fractions_to_tomorrow() {
+    let server = server_at("2024-06-01T06:00:00Z");
+
+    let midday = server
+        .time_until_day_fraction(0.5, None, None)
+        .await
+        .unwrap();
+    assert_eq!(midday.total_seconds, 6 * 3_600);
+    assert_eq!((midday.hours, midday.minutes), (6, 0));
+
+    // At noon the quarter-day mark (06:00) has passed, so the next one is tomorrow's
+    let quarter = server
+        .time_until_day_fraction(0.25, None, Some("2024-06-01T12:00:00Z".to_string()))
+        .await
+        .unwrap();
+    assert_eq!(quarter.total_seconds, 18 * 3_600);
+    assert!(!quarter.is_negative);
+}
+
+#[tokio::test]
+async fn fuzzy_hours_rounds_to_the_nearest_half_hour() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let fuzzy = |end: &str| server.fuzzy_hours("2024-06-01 10:00".to_string(), end.to_string());
+
+    assert_eq!(fuzzy("2024-06-01 12:00").await.unwrap(), "2 hours");
+    assert_eq!(
+        fuzzy("2024-06-01 12:20").await.unwrap(),
+        "about 2 and a half hours"
+    );
+    assert_eq!(
+        fuzzy("2024-06-01 12:30").await.unwrap(),
+        "2 and a half hours"
+    );
+}
+
+#[tokio::test]
+async fn next_round_epoch_finds_the_next_multiple_of_a_million() {
+    // 1717243200 seconds after the epoch
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let next = full(server.next_round_epoch(6, None, None).await.unwrap());
+    assert_eq!(next.timestamp, "2024-06-10T06:13:20+00:00");
+    assert_eq!(
+        chrono::DateTime::parse_from_rfc3339(&next.timestamp)
+            .unwrap()
+            .timestamp(),
+        1_718_000_000
+    );
+
+    // A timestamp already on a multiple moves on to the next one
+    let after = full(
+        server
+            .next_round_epoch(6, Some("2024-06-10T06:13:20Z".to_string()), None)
+            .await
+            .unwrap(),
+    );
+    assert_eq!(after.timestamp, "2024-06-21T20:00:00+00:00");
+}
+
+#[tokio::test]
+async fn stopwatch_format_keeps_counting_hours_past_a_day() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let stopwatch = |end: &str, show_millis: Option<bool>| {
+        server.stopwatch_format(
+            "2024-06-01T00:00:00Z".to_string(),
+            end.to_string(),
+            show_millis,
+        )
+    };
+
+    let short = stopwatch("2024-06-01T00:42:07Z", None).await;
+    assert_eq!(short.unwrap(), "00:42:07");
+    let long = stopwatch("2024-06-02T03:04:05Z", None).await;
+    assert_eq!(long.unwrap(), "27:04:05");
+    let millis = stopwatch("2024-06-01T00:00:01.250Z", Some(true)).await;
+    assert_eq!(millis.unwrap(), "00:00:01.250");
+}
+
+#[tokio::test]
+async fn offset_precise_keeps_sub_second_offsets() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let shifted = server
+        .offset_precise("2024-06-01T12:00:00Z".to_string(), 0.5, None, None)
+        .await
+        .unwrap();
+    let shifted = chrono::DateTime::parse_from_rfc3339(&full(shifted).timestamp).unwrap();
+    assert_eq!(shifted.timestamp(), 1_717_243_200);
+    assert_eq!(shifted.timestamp_subsec_nanos(), 500_000_000);
+}
+
+#[tokio::test]
+async fn life_progress_is_clamped_at_one() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let progress = server
+        .life_progress("1992-06-01".to_string(), 80, None)
+        .await
+        .unwrap();
+    assert!((progress - 0.4).abs() < 1e-3, "{progress}");
+
+    let outlived = server
+        .life_progress("1900-01-01".to_string(), 80, None)
+        .await
+        .unwrap();
+    assert_eq!(outlived, 1.0);
+}
+
+#[tokio::test]
+async fn parse_duration_accepts_each_notation() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    for notation in ["PT1H30M15S", "1h30m15s", "01:30:15"] {
+        let duration = server.parse_duration(notation.to_string()).await.unwrap();
+        assert_eq!(duration.total_seconds, 5_415, "{notation}");
+        assert_eq!(duration.iso8601, "PT1H30M15S", "{notation}");
+    }
+}
+
+#[tokio::test]
+async fn night_hours_in_window_wraps_past_midnight() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let night_hours = |start: &str, end: &str| {
+        server.night_hours_in_window(start.to_string(), end.to_string(), 22, 6, None)
+    };
+
+    let all_night = night_hours("2024-06-01 23:00", "2024-06-02 05:00").await;
+    assert_eq!(all_night.unwrap(), 6.0);
+    let evening = night_hours("2024-06-01 20:00", "2024-06-02 00:30").await;
+    assert_eq!(evening.unwrap(), 2.5);
+    let daytime = night_hours("2024-06-01 09:00", "2024-06-01 17:00").await;
+    assert_eq!(daytime.unwrap(), 0.0);
+}
+
+#[tokio::test]
+async fn elapsed_beats_scales_by_tempo() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let beats = server
+        .elapsed_beats(
+            "2024-06-01T12:00:00Z".to_string(),
+            "2024-06-01T12:01:00Z".to_string(),
+            120.0,
+        )
+        .await
+        .unwrap();
+    assert_eq!(beats, 120.0);
+}
+
+#[tokio::test]
+async fn progress_between_is_clamped_to_the_milestones() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let progress = |at: &str| {
+        server.progress_between(
+            "2024-06-01 00:00".to_string(),
+            "2024-06-03 00:00".to_string(),
+            Some(at.to_string()),
+        )
+    };
+
+    assert_eq!(progress("2024-05-31 12:00").await.unwrap(), 0.0);
+    assert_eq!(progress("2024-06-02 00:00").await.unwrap(), 0.5);
+    assert_eq!(progress("2024-06-04 00:00").await.unwrap(), 1.0);
+}
+
+#[tokio::test]
+async fn extreme_hour_offsets_are_out_of_range() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    for hours in [i32::MAX, i32::MIN] {
+        let shifted = server
+            .calculate_time_offset("now".to_string(), Some(hours), None, None, None)
+            .await;
+        assert_eq!(error_code(shifted), "out_of_range", "{hours} hours");
+    }
+    let combined = server
+        .calculate_time_offset(
+            "now".to_string(),
+            Some(i32::MAX),
+            Some("+9999999 weeks".to_string()),
+            None,
+            None,
+        )
+        .await;
+    assert_eq!(error_code(combined), "out_of_range");
+}
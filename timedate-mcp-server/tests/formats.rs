@@ -0,0 +1,115 @@
+//! Rendering and recognising written timestamps
+
+mod common;
+
+use common::server_at;
+
+#[tokio::test]
+async fn rfc3339_z_uses_a_trailing_z() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let rendered = server
+        .to_rfc3339_z("2024-06-01T14:30:00+02:00".to_string())
+        .await
+        .unwrap();
+    assert_eq!(rendered, "2024-06-01T12:30:00Z");
+    assert!(!rendered.ends_with("+00:00"));
+
+    let offset = server
+        .to_rfc3339_offset("2024-06-01T12:30:00Z".to_string(), None)
+        .await
+        .unwrap();
+    assert_eq!(offset, "2024-06-01T12:30:00+00:00");
+}
+
+#[tokio::test]
+async fn infer_hour_format_detects_the_clock_convention() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let twelve = server
+        .infer_hour_format("3:30 PM".to_string())
+        .await
+        .unwrap();
+    assert_eq!(twelve.detected_format, "12-hour");
+    assert!(twelve.is_12_hour);
+    assert_eq!(twelve.current_time_24h, "15:30:00");
+
+    let twenty_four = server.infer_hour_format("15:30".to_string()).await.unwrap();
+    assert_eq!(twenty_four.detected_format, "24-hour");
+    assert!(!twenty_four.is_12_hour);
+    assert_eq!(twenty_four.current_time_12h, "03:30:00 PM");
+
+    let ambiguous = server.infer_hour_format("09:30".to_string()).await.unwrap();
+    assert_eq!(ambiguous.detected_format, "ambiguous");
+}
+
+#[tokio::test]
+async fn normalize_to_utc_reads_offset_less_inputs_in_the_assumed_zone() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let normalized = server
+        .normalize_to_utc(
+            vec![
+                "2024-06-01 09:00:00".to_string(),
+                "2024-06-01T09:00:00+02:00".to_string(),
+                "2024-06-01T13:00:00Z".to_string(),
+            ],
+            "America/New_York".to_string(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        normalized,
+        [
+            "2024-06-01T13:00:00Z",
+            "2024-06-01T07:00:00Z",
+            "2024-06-01T13:00:00Z",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn decompose_reports_each_component_of_an_rfc3339_input() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let parts = server
+        .decompose("2024-03-09T14:05:09.123+05:30".to_string(), None)
+        .await
+        .unwrap();
+    // Read in the default UTC zone
+    assert_eq!((parts.year, parts.month, parts.day), (2024, 3, 9));
+    assert_eq!((parts.hour, parts.minute, parts.second), (8, 35, 9));
+    assert_eq!(parts.nanosecond, 123_000_000);
+    assert_eq!(parts.weekday, "Saturday");
+    assert_eq!(parts.offset_seconds, 0);
+
+    let local = server
+        .decompose(
+            "2024-03-09T14:05:09.123+05:30".to_string(),
+            Some("Asia/Kolkata".to_string()),
+        )
+        .await
+        .unwrap();
+    assert_eq!((local.hour, local.minute), (14, 5));
+    assert_eq!(local.offset_seconds, 19_800);
+}
+
+#[tokio::test]
+async fn every_supported_format_example_parses() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let formats = server.supported_formats().await.unwrap();
+    assert!(!formats.is_empty());
+    for format in formats {
+        let report = server
+            .parse_datetime(format.example.clone(), None)
+            .await
+            .unwrap();
+        assert!(
+            report.matched,
+            "{} example {:?}",
+            format.name, format.example
+        );
+        assert!(report.utc.is_some(), "{}: {:?}", format.name, report.error);
+    }
+}
@@ -0,0 +1,209 @@
+//! Oversized and out-of-bounds arguments are rejected with `invalid_input` instead of being
+//! parsed, and never take the server down
+
+use std::time::{Duration, Instant};
+
+use chrono::TimeZone;
+use pulseengine_mcp_protocol::{CallToolRequestParam, PaginatedRequestParam};
+use pulseengine_mcp_server::McpBackend;
+use timedate_mcp_server::{FixedClock, TimeDateError, TimeDateServer};
+
+fn server() -> TimeDateServer {
+    TimeDateServer::with_clock(FixedClock(
+        chrono::Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap(),
+    ))
+}
+
+fn assert_invalid_input<T: std::fmt::Debug>(result: anyhow::Result<T>, argument: &str) {
+    let err = result.expect_err("argument should be rejected");
+    match err.downcast_ref::<TimeDateError>() {
+        Some(TimeDateError::InvalidInput { argument: got, .. }) => assert_eq!(*got, argument),
+        other => panic!("expected invalid_input for {argument}, got {other:?} ({err})"),
+    }
+}
+
+#[tokio::test]
+async fn oversized_strings_are_rejected_quickly() {
+    let server = server();
+    let huge = "A".repeat(1 << 20);
+    let started = Instant::now();
+
+    assert_invalid_input(
+        server.get_current_time(Some(huge.clone()), None).await,
+        "timezone",
+    );
+    assert_invalid_input(
+        server.get_time_at(huge.clone(), None, None).await,
+        "date/time",
+    );
+    assert_invalid_input(
+        server
+            .format_datetime("now".to_string(), huge.clone(), None)
+            .await,
+        "format pattern",
+    );
+    assert_invalid_input(
+        server.list_timezones(Some(huge.clone()), None).await,
+        "filter",
+    );
+    assert_invalid_input(
+        server
+            .next_cron_times(huge, "now".to_string(), 1, None, None)
+            .await,
+        "cron expression",
+    );
+
+    // The timezone suggestion search would take far longer on a megabyte of input
+    assert!(started.elapsed() < Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn control_characters_are_rejected_in_names_and_patterns() {
+    let server = server();
+
+    assert_invalid_input(
+        server
+            .get_current_time(Some("Europe/\u{0}Berlin".to_string()), None)
+            .await,
+        "timezone",
+    );
+    assert_invalid_input(
+        server
+            .format_datetime("now".to_string(), "%H\n%M".to_string(), None)
+            .await,
+        "format pattern",
+    );
+}
+
+#[tokio::test]
+async fn counts_lists_and_spans_are_bounded() {
+    let server = server();
+
+    assert_invalid_input(
+        server
+            .next_cron_times("0 9 * * *".to_string(), "now".to_string(), 5000, None, None)
+            .await,
+        "count",
+    );
+    assert_invalid_input(
+        server
+            .convert_to_many(
+                "now".to_string(),
+                "UTC".to_string(),
+                vec!["UTC".to_string(); 1001],
+            )
+            .await,
+        "to_timezones",
+    );
+    assert_invalid_input(
+        server
+            .hourly_grid(
+                "2024-01-01".to_string(),
+                "2025-01-01".to_string(),
+                "UTC".to_string(),
+            )
+            .await,
+        "window",
+    );
+}
+
+#[tokio::test]
+async fn years_beyond_the_supported_range_are_rejected() {
+    let server = server();
+
+    assert_invalid_input(
+        server.holiday_week("+262142-12-31".to_string()).await,
+        "year",
+    );
+    assert_invalid_input(
+        server
+            .get_time_at("+12000-01-01 00:00".to_string(), None, None)
+            .await,
+        "year",
+    );
+    assert_invalid_input(server.is_leap_year(20000).await, "year");
+    assert!(server.is_leap_year(9996).await.unwrap());
+}
+
+#[tokio::test]
+async fn server_keeps_answering_after_extreme_dates() {
+    let server = server();
+    let call = |name: &str, arguments: serde_json::Value| CallToolRequestParam {
+        name: name.to_string(),
+        arguments: Some(arguments),
+    };
+
+    for (name, arguments) in [
+        ("holiday_week", serde_json::json!({"date": "+262142-12-31"})),
+        (
+            "dst_countdown",
+            serde_json::json!({"from": "+262142-12-31 23:30", "timezone": "America/New_York"}),
+        ),
+        (
+            "round_datetime",
+            serde_json::json!({"date_time": "9999-12-31 23:59", "granularity": "day", "mode": "ceil"}),
+        ),
+    ] {
+        let _ = server.call_tool(call(name, arguments)).await;
+    }
+
+    assert!(server
+        .call_tool(call("get_current_time", serde_json::json!({})))
+        .await
+        .is_ok());
+}
+
+#[tokio::test]
+async fn extreme_arguments_never_panic_any_tool() {
+    let server = TimeDateServer::with_clock(FixedClock(
+        chrono::Utc
+            .with_ymd_and_hms(9999, 12, 31, 23, 0, 0)
+            .unwrap(),
+    ));
+    let tools = server
+        .list_tools(PaginatedRequestParam { cursor: None })
+        .await
+        .unwrap()
+        .tools;
+
+    // The latest and earliest instants the parsers accept, paired with the widest numbers
+    let extremes = [
+        ("9999-12-31 23:59", serde_json::json!(i64::MAX), 1e308),
+        ("-9999-01-01 00:00", serde_json::json!(i64::MIN), -1e308),
+    ];
+    let mut panicked = Vec::new();
+    for tool in tools.iter().filter(|tool| tool.name != "get_ntp_time") {
+        let schema = serde_json::to_value(&tool.input_schema).unwrap();
+        let Some(properties) = schema["properties"].as_object() else {
+            continue;
+        };
+        for (date_time, integer, number) in &extremes {
+            let arguments = properties
+                .iter()
+                .filter_map(|(name, property)| {
+                    let value = match property["type"].as_str() {
+                        Some("string") => serde_json::json!(date_time),
+                        Some("integer") => integer.clone(),
+                        Some("number") => serde_json::json!(number),
+                        Some("boolean") => serde_json::json!(true),
+                        _ => return None,
+                    };
+                    Some((name.clone(), value))
+                })
+                .collect();
+            let server = server.clone();
+            let request = CallToolRequestParam {
+                name: tool.name.clone(),
+                arguments: Some(serde_json::Value::Object(arguments)),
+            };
+            if tokio::spawn(async move { server.call_tool(request).await })
+                .await
+                .is_err()
+            {
+                panicked.push(tool.name.clone());
+            }
+        }
+    }
+
+    assert!(panicked.is_empty(), "tools panicked: {panicked:?}");
+}
@@ -0,0 +1,127 @@
+//! Sunrise, sunset, seasons and mean solar time
+
+mod common;
+
+use chrono::{DateTime, FixedOffset};
+use common::{full, server_at};
+
+fn instant(rfc3339: &str) -> DateTime<FixedOffset> {
+    DateTime::parse_from_rfc3339(rfc3339).expect("RFC3339 output")
+}
+
+/// Whether two instants are within `minutes` of each other
+fn within_minutes(actual: &str, expected: &str, minutes: i64) -> bool {
+    (instant(actual) - instant(expected)).num_minutes().abs() <= minutes
+}
+
+#[tokio::test]
+async fn city_sun_times_match_published_london_times() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    // Published times for London on the 2024 June solstice: sunrise 04:43, sunset 21:21 BST
+    let sun = server
+        .city_sun_times("London".to_string(), "2024-06-21".to_string())
+        .await
+        .unwrap();
+    assert_eq!(sun.timezone, "Europe/London");
+    assert_eq!(sun.status, "normal");
+    let sunrise = sun.sunrise.unwrap();
+    let sunset = sun.sunset.unwrap();
+    assert!(
+        within_minutes(&sunrise, "2024-06-21T04:43:00+01:00", 2),
+        "{sunrise}"
+    );
+    assert!(
+        within_minutes(&sunset, "2024-06-21T21:21:00+01:00", 2),
+        "{sunset}"
+    );
+}
+
+#[tokio::test]
+async fn daylight_fraction_is_half_at_the_equator_and_long_in_northern_summer() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    for date in ["2024-01-15", "2024-04-15", "2024-07-15", "2024-10-15"] {
+        let fraction = server
+            .daylight_fraction(0.0, 0.0, date.to_string())
+            .await
+            .unwrap();
+        assert!((fraction - 0.5).abs() < 0.02, "{date}: {fraction}");
+    }
+
+    // Helsinki has about 19 hours of daylight at midsummer, Tromsø has the midnight sun
+    let helsinki = server
+        .daylight_fraction(60.17, 24.94, "2024-06-21".to_string())
+        .await
+        .unwrap();
+    assert!(helsinki > 0.75 && helsinki < 0.85, "{helsinki}");
+    let tromso = server
+        .daylight_fraction(69.65, 18.96, "2024-06-21".to_string())
+        .await
+        .unwrap();
+    assert_eq!(tromso, 1.0);
+}
+
+#[tokio::test]
+async fn astronomical_seasons_place_the_march_equinox_on_march_20() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    // The 2024 March equinox was at 03:06 UTC on 20 March
+    let seasons = server
+        .astronomical_seasons(2024, "northern".to_string())
+        .await
+        .unwrap();
+    assert!(
+        within_minutes(&seasons.spring_start, "2024-03-20T03:06:00Z", 24 * 60),
+        "{}",
+        seasons.spring_start
+    );
+
+    // The southern hemisphere's autumn starts at the same instant
+    let southern = server
+        .astronomical_seasons(2024, "southern".to_string())
+        .await
+        .unwrap();
+    assert_eq!(southern.autumn_start, seasons.spring_start);
+}
+
+#[tokio::test]
+async fn local_mean_time_runs_ahead_east_of_the_zone_meridian() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    // Warsaw lies six degrees east of the 15°E meridian of Central European Time, so its mean
+    // solar time is about 24 minutes ahead of the clock
+    let mean = server
+        .local_mean_time(
+            21.01,
+            "2024-01-15 12:00".to_string(),
+            "Europe/Warsaw".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    let mean = full(mean);
+    assert!(
+        mean.timestamp.starts_with("2024-01-15T12:24:02"),
+        "{}",
+        mean.timestamp
+    );
+}
+
+#[tokio::test]
+async fn solar_extremes_put_the_earliest_sunrise_near_the_summer_solstice() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let london = server
+        .solar_extremes(51.5074, -0.1278, 2024, "Europe/London".to_string())
+        .await
+        .unwrap();
+    // London's earliest sunrise falls a few days before the 21 June solstice
+    let earliest = instant(&london.earliest_sunrise.unwrap());
+    let solstice = instant("2024-06-21T00:00:00+01:00");
+    assert!((earliest - solstice).num_days().abs() <= 7, "{earliest}");
+    // and its latest sunset a few days after
+    let latest = instant(&london.latest_sunset.unwrap());
+    assert!((latest - solstice).num_days().abs() <= 7, "{latest}");
+    assert_eq!((london.polar_days, london.polar_nights), (0, 0));
+}
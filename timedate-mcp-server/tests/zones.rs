@@ -0,0 +1,478 @@
+//! Timezone offsets, conversions and DST lookups
+
+mod common;
+
+use common::{full, server_at};
+
+#[tokio::test]
+async fn hours_apart_handles_half_hour_and_cross_date_zones() {
+    let server = server_at("2024-01-15T12:00:00Z");
+
+    let kolkata = server
+        .hours_apart("UTC".to_string(), "Asia/Kolkata".to_string(), None)
+        .await
+        .unwrap();
+    assert_eq!(kolkata, 5.5);
+
+    // Los Angeles is on PST (-8) in January and Tokyo on JST (+9)
+    let la_tokyo = server
+        .hours_apart(
+            "America/Los_Angeles".to_string(),
+            "Asia/Tokyo".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(la_tokyo, 17.0);
+    let tokyo_la = server
+        .hours_apart(
+            "Asia/Tokyo".to_string(),
+            "America/Los_Angeles".to_string(),
+            Some("2024-07-15T12:00:00Z".to_string()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(tokyo_la, -16.0);
+}
+
+#[tokio::test]
+async fn zones_crossed_reports_eastbound_and_westbound_flights() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    // 18:00 in New York (22:00 UTC) plus seven hours lands at 06:00 in London
+    let east = server
+        .zones_crossed(
+            "America/New_York".to_string(),
+            "Europe/London".to_string(),
+            "2024-06-01 18:00".to_string(),
+            420,
+        )
+        .await
+        .unwrap();
+    assert_eq!(east.arrival.timestamp, "2024-06-02T06:00:00+01:00");
+    assert_eq!(east.jet_lag_hours, 5.0);
+    assert_eq!(east.zones_crossed, 5);
+    assert_eq!(east.net_clock_change_minutes, 720);
+
+    // 17:00 in Tokyo (08:00 UTC) plus ten hours lands at 11:00 the same day in Los Angeles
+    let west = server
+        .zones_crossed(
+            "Asia/Tokyo".to_string(),
+            "America/Los_Angeles".to_string(),
+            "2024-06-01 17:00".to_string(),
+            600,
+        )
+        .await
+        .unwrap();
+    assert_eq!(west.arrival.timestamp, "2024-06-01T11:00:00-07:00");
+    assert_eq!(west.jet_lag_hours, -16.0);
+    assert_eq!(west.zones_crossed, 16);
+    assert_eq!(west.net_clock_change_minutes, -360);
+}
+
+#[tokio::test]
+async fn seconds_in_day_reflects_dst_transitions() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let seconds =
+        |date: &str| server.seconds_in_day(date.to_string(), "America/New_York".to_string());
+
+    assert_eq!(seconds("2024-06-01").await.unwrap(), 86_400);
+    assert_eq!(seconds("2024-03-10").await.unwrap(), 82_800);
+    assert_eq!(seconds("2024-11-03").await.unwrap(), 90_000);
+}
+
+#[tokio::test]
+async fn zone_year_summary_lists_both_offsets_only_for_dst_zones() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let berlin = server
+        .zone_year_summary("Europe/Berlin".to_string(), 2024)
+        .await
+        .unwrap();
+    assert_eq!(berlin.standard_offset, "+01:00");
+    assert_eq!(berlin.dst_offset.as_deref(), Some("+02:00"));
+    assert_eq!(berlin.transitions, 2);
+    assert_eq!(berlin.abbreviations, ["CET", "CEST"]);
+
+    let tokyo = server
+        .zone_year_summary("Asia/Tokyo".to_string(), 2024)
+        .await
+        .unwrap();
+    assert_eq!(tokyo.standard_offset, "+09:00");
+    assert_eq!(tokyo.dst_offset, None);
+    assert_eq!(tokyo.transitions, 0);
+    assert_eq!(tokyo.abbreviations, ["JST"]);
+}
+
+#[tokio::test]
+async fn schedule_summary_names_both_local_times_and_the_direction() {
+    let server = server_at("2024-06-03T13:00:00Z");
+
+    let ahead = server
+        .schedule_summary(
+            "America/New_York".to_string(),
+            "Asia/Kolkata".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    assert!(ahead.contains("9:00 AM Monday"), "{ahead}");
+    assert!(ahead.contains("6:30 PM Monday"), "{ahead}");
+    assert!(ahead.contains("9 hours 30 minutes ahead"), "{ahead}");
+
+    let behind = server
+        .schedule_summary(
+            "Europe/London".to_string(),
+            "America/Los_Angeles".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    assert!(behind.contains("2:00 PM Monday"), "{behind}");
+    assert!(behind.contains("6:00 AM Monday"), "{behind}");
+    assert!(behind.contains("8 hours behind"), "{behind}");
+}
+
+#[tokio::test]
+async fn aligned_local_times_start_once_two_zones_share_an_offset() {
+    // London leaves BST for GMT, which Abidjan keeps all year, on 27 October 2024
+    let server = server_at("2024-10-24T08:00:00Z");
+
+    let aligned = server
+        .aligned_local_times(
+            "12:00".to_string(),
+            "Europe/London".to_string(),
+            "Africa/Abidjan".to_string(),
+            5,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        aligned,
+        [
+            "2024-10-27T12:00:00Z",
+            "2024-10-28T12:00:00Z",
+            "2024-10-29T12:00:00Z",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn offset_minutes_returns_signed_whole_minutes() {
+    let server = server_at("2024-01-15T12:00:00Z");
+
+    let kolkata = server
+        .offset_minutes("Asia/Kolkata".to_string(), None)
+        .await;
+    assert_eq!(kolkata.unwrap(), 330);
+    let los_angeles = server
+        .offset_minutes("America/Los_Angeles".to_string(), None)
+        .await;
+    assert_eq!(los_angeles.unwrap(), -480);
+}
+
+#[tokio::test]
+async fn offset_common_name_lists_abbreviations_for_an_offset() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let utc = server.offset_common_name("+00:00".to_string()).await;
+    assert_eq!(utc.unwrap(), ["GMT", "UTC", "WET"]);
+    let eastern = server.offset_common_name("-05:00".to_string()).await;
+    assert_eq!(eastern.unwrap(), ["EST", "CDT"]);
+}
+
+#[tokio::test]
+async fn local_hours_for_utc_reads_each_zone_at_one_utc_hour() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let row = server
+        .local_hours_for_utc(
+            14,
+            "2024-06-03".to_string(),
+            vec![
+                "America/New_York".to_string(),
+                "Europe/London".to_string(),
+                "Asia/Tokyo".to_string(),
+            ],
+        )
+        .await
+        .unwrap();
+    let cells: Vec<(&str, u32, bool)> = row
+        .iter()
+        .map(|cell| {
+            (
+                cell.timezone.as_str(),
+                cell.local_hour,
+                cell.is_working_hours,
+            )
+        })
+        .collect();
+    assert_eq!(
+        cells,
+        [
+            ("America/New_York", 10, true),
+            ("Europe/London", 15, true),
+            ("Asia/Tokyo", 23, false),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn dst_countdown_reaches_the_next_clock_change() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    // New York falls back at 06:00 UTC on 3 November 2024
+    let new_york = server
+        .dst_countdown("America/New_York".to_string(), None)
+        .await
+        .unwrap()
+        .expect("New York changes its clocks");
+    assert_eq!(new_york.total_seconds, 154 * 86_400 + 18 * 3_600);
+    assert_eq!((new_york.days, new_york.hours), (154, 18));
+
+    let utc = server.dst_countdown("UTC".to_string(), None).await.unwrap();
+    assert!(utc.is_none());
+}
+
+#[tokio::test]
+async fn closest_to_local_time_picks_the_zone_nearest_the_target() {
+    let server = server_at("2024-06-03T13:20:00Z");
+
+    // 09:20 in New York, 14:20 in London, 22:20 in Tokyo and 08:20 in Chicago
+    let closest = server
+        .closest_to_local_time(
+            "09:00".to_string(),
+            vec![
+                "Europe/London".to_string(),
+                "Asia/Tokyo".to_string(),
+                "America/New_York".to_string(),
+                "America/Chicago".to_string(),
+            ],
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(closest.timezone, "America/New_York");
+    assert_eq!(closest.minutes_from_target, 20);
+}
+
+#[tokio::test]
+async fn time_gmt_offset_reads_legacy_offset_labels() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let at_offset = |gmt_offset: &str| {
+        server.time_gmt_offset(
+            "2024-06-01T12:00:00Z".to_string(),
+            gmt_offset.to_string(),
+            None,
+        )
+    };
+
+    for (gmt_offset, timestamp, utc_offset) in [
+        ("GMT+5", "2024-06-01T17:00:00+05:00", "+05:00"),
+        ("UTC-3", "2024-06-01T09:00:00-03:00", "-03:00"),
+        ("GMT+5:30", "2024-06-01T17:30:00+05:30", "+05:30"),
+    ] {
+        let time = full(at_offset(gmt_offset).await.unwrap());
+        assert_eq!(time.timestamp, timestamp);
+        assert_eq!(time.utc_offset, utc_offset);
+    }
+}
+
+#[tokio::test]
+async fn is_dst_follows_each_hemisphere_and_is_false_for_fixed_offsets() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let is_dst = |date_time: &str, timezone: &str| {
+        let time = server.get_time_at(date_time.to_string(), Some(timezone.to_string()), None);
+        async move { full(time.await.unwrap()).is_dst }
+    };
+
+    assert!(is_dst("2024-07-15 12:00", "America/New_York").await);
+    assert!(!is_dst("2024-01-15 12:00", "America/New_York").await);
+    // Southern-hemisphere summer time runs over the new year
+    assert!(is_dst("2024-01-15 12:00", "Australia/Sydney").await);
+    assert!(!is_dst("2024-07-15 12:00", "Australia/Sydney").await);
+    assert!(!is_dst("2024-07-15 12:00", "UTC").await);
+    assert!(!is_dst("2024-07-15 12:00", "Etc/GMT-5").await);
+    assert!(!is_dst("2024-07-15 12:00", "Asia/Tokyo").await);
+
+    let fixed = server
+        .time_gmt_offset(
+            "2024-07-15T12:00:00Z".to_string(),
+            "GMT+5".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+    assert!(!full(fixed).is_dst);
+}
+
+#[tokio::test]
+async fn local_noon_utc_moves_against_the_zone_offset() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let noon =
+        |timezone: &str| server.local_noon_utc(timezone.to_string(), "2024-06-03".to_string());
+
+    let tokyo = noon("Asia/Tokyo").await.unwrap();
+    assert_eq!(
+        chrono::DateTime::parse_from_rfc3339(&tokyo).unwrap(),
+        chrono::DateTime::parse_from_rfc3339("2024-06-03T03:00:00Z").unwrap()
+    );
+    let new_york = noon("America/New_York").await.unwrap();
+    assert_eq!(
+        chrono::DateTime::parse_from_rfc3339(&new_york).unwrap(),
+        chrono::DateTime::parse_from_rfc3339("2024-06-03T16:00:00Z").unwrap()
+    );
+}
+
+#[tokio::test]
+async fn list_timezones_returns_every_match_unless_limited() {
+    let server = server_at("2024-06-01T12:00:00Z");
+
+    let europe = server
+        .list_timezones(Some("Europe".to_string()), None)
+        .await
+        .unwrap();
+    let every_europe_zone: Vec<&str> = chrono_tz::TZ_VARIANTS
+        .iter()
+        .map(|tz| tz.name())
+        .filter(|name| name.starts_with("Europe/"))
+        .collect();
+    assert!(every_europe_zone.len() > 50);
+    for name in &every_europe_zone {
+        assert!(europe.iter().any(|listed| listed == name), "missing {name}");
+    }
+
+    let limited = server
+        .list_timezones(Some("Europe".to_string()), Some(5))
+        .await
+        .unwrap();
+    assert_eq!(limited, europe[..5]);
+    let resource = server
+        .timezone_list_limited_resource("Europe".to_string(), "5".to_string())
+        .await
+        .unwrap();
+    assert_eq!(resource, limited);
+}
+
+#[tokio::test]
+async fn get_dst_transitions_covers_both_hemispheres_and_non_dst_zones() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let transitions = |timezone: &str| server.get_dst_transitions(timezone.to_string(), 2024);
+
+    let london = transitions("Europe/London").await.unwrap();
+    let summary: Vec<(&str, &str, &str)> = london
+        .iter()
+        .map(|t| {
+            (
+                t.kind.as_str(),
+                t.offset_before.as_str(),
+                t.offset_after.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("spring_forward", "+00:00", "+01:00"),
+            ("fall_back", "+01:00", "+00:00"),
+        ]
+    );
+    assert_eq!(london[0].instant, "2024-03-31T01:00:00Z");
+    assert_eq!(london[1].instant, "2024-10-27T01:00:00Z");
+
+    assert!(transitions("Asia/Tokyo").await.unwrap().is_empty());
+
+    // Sydney falls back in April and springs forward in October
+    let sydney = transitions("Australia/Sydney").await.unwrap();
+    let kinds: Vec<&str> = sydney.iter().map(|t| t.kind.as_str()).collect();
+    assert_eq!(kinds, ["fall_back", "spring_forward"]);
+    assert_eq!(sydney[0].instant, "2024-04-06T16:00:00Z");
+    assert_eq!(sydney[1].instant, "2024-10-05T16:00:00Z");
+}
+
+#[tokio::test]
+async fn hourly_grid_skips_and_repeats_hours_across_dst() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let grid = |date: &str| {
+        server.hourly_grid(
+            format!("{date} 00:00"),
+            format!("{date} 23:00"),
+            "America/New_York".to_string(),
+        )
+    };
+
+    assert_eq!(grid("2024-06-03").await.unwrap().len(), 24);
+
+    let spring = grid("2024-03-10").await.unwrap();
+    assert_eq!(spring.len(), 23);
+    assert!(!spring.iter().any(|hour| hour.contains("T02:00")));
+
+    let fall = grid("2024-11-03").await.unwrap();
+    assert_eq!(fall.len(), 25);
+    assert!(fall.contains(&"2024-11-03T01:00:00-04:00".to_string()));
+    assert!(fall.contains(&"2024-11-03T01:00:00-05:00".to_string()));
+}
+
+#[tokio::test]
+async fn is_transition_instant_detects_clock_changes() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let check = |date_time: &str| {
+        server.is_transition_instant("America/New_York".to_string(), date_time.to_string())
+    };
+
+    // New York springs forward at 07:00 UTC on 10 March 2024
+    let at_change = check("2024-03-10T07:00:00Z").await.unwrap();
+    assert!(at_change.is_transition && at_change.near_transition);
+    assert_eq!(at_change.seconds_from_transition, Some(0));
+    assert_eq!(at_change.offset_before.as_deref(), Some("-05:00"));
+    assert_eq!(at_change.offset_after.as_deref(), Some("-04:00"));
+
+    let shortly_before = check("2024-03-10T06:30:00Z").await.unwrap();
+    assert!(!shortly_before.is_transition && shortly_before.near_transition);
+    assert_eq!(shortly_before.seconds_from_transition, Some(-1_800));
+
+    let far_away = check("2024-06-01T12:00:00Z").await.unwrap();
+    assert!(!far_away.is_transition && !far_away.near_transition);
+    assert_eq!(far_away.nearest_transition, None);
+}
+
+#[tokio::test]
+async fn from_day_fraction_scales_by_the_actual_day_length() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let halfway = |date: &str| {
+        server.from_day_fraction(
+            date.to_string(),
+            0.5,
+            Some("America/New_York".to_string()),
+            None,
+        )
+    };
+
+    let normal = full(halfway("2024-06-03").await.unwrap());
+    assert_eq!(normal.timestamp, "2024-06-03T12:00:00-04:00");
+
+    // 10 March 2024 is 23 hours long, so halfway is 11.5 hours after midnight EST
+    let spring_forward = full(halfway("2024-03-10").await.unwrap());
+    assert_eq!(spring_forward.timestamp, "2024-03-10T12:30:00-04:00");
+}
+
+#[tokio::test]
+async fn timezone_offset_difference_changes_when_only_one_zone_has_dst() {
+    let server = server_at("2024-06-01T12:00:00Z");
+    let difference = |at: &str| {
+        server.timezone_offset_difference(
+            "Europe/Berlin".to_string(),
+            "Asia/Tokyo".to_string(),
+            Some(at.to_string()),
+        )
+    };
+
+    let winter = difference("2024-01-15T12:00:00Z").await.unwrap();
+    assert_eq!(winter.minutes, 8 * 60);
+    assert_eq!(winter.description, "Tokyo is 8 hours ahead of Berlin");
+
+    // Berlin is on CEST while Tokyo has no DST
+    let summer = difference("2024-07-15T12:00:00Z").await.unwrap();
+    assert_eq!(summer.minutes, 7 * 60);
+    assert_eq!(summer.description, "Tokyo is 7 hours ahead of Berlin");
+}